@@ -65,7 +65,7 @@ fuzz_target!(|input: CliOutputInput| {
     if !input.title.is_empty() {
         let search_term: String = input.title.chars().take(10).collect();
         if !search_term.is_empty() {
-            let _ = db.search_issues(&search_term);
+            let _ = db.search_issues(&search_term, None, None, 20, 0);
         }
     }
 
@@ -0,0 +1,616 @@
+//! Date-range-filtered rollups over issues, labels, and time entries, modeled on the
+//! analytics-filter layer in comparable trackers: every query here takes an optional
+//! `from`/`to` window plus optional `label`/`priority` filters and builds its SQL with bound
+//! parameters the same way `Database::list_issues` assembles its dynamic `WHERE` clause.
+//! Lives alongside `migrations` as a module of free functions over `&Connection` rather than
+//! `Database` methods, since `commands::migrate` already established that pattern for
+//! cross-cutting reporting logic that doesn't belong to any single table's CRUD surface.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+/// How `burndown` buckets `created_at`/`closed_at` timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    Day,
+    Week,
+}
+
+impl Granularity {
+    fn strftime_format(self) -> &'static str {
+        match self {
+            Granularity::Day => "%Y-%m-%d",
+            Granularity::Week => "%Y-W%W",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BurndownPoint {
+    pub bucket: String,
+    pub opened: i64,
+    pub closed: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeToClose {
+    pub priority: String,
+    pub label: Option<String>,
+    pub avg_seconds: f64,
+    pub sample_size: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackedTime {
+    pub issue_id: i64,
+    pub label: Option<String>,
+    pub total_seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSummary {
+    pub session_id: i64,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub issues_touched: i64,
+    pub time_logged_seconds: i64,
+}
+
+/// Appends a `column BETWEEN/>=/<=` bound on `from`/`to` to `conditions`/`params`, so every
+/// query in this module filters its date column the same way.
+fn push_date_range(
+    conditions: &mut Vec<String>,
+    params: &mut Vec<Box<dyn rusqlite::ToSql>>,
+    column: &str,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) {
+    if let Some(from) = from {
+        conditions.push(format!("{} >= ?", column));
+        params.push(Box::new(from.to_rfc3339()));
+    }
+    if let Some(to) = to {
+        conditions.push(format!("{} <= ?", column));
+        params.push(Box::new(to.to_rfc3339()));
+    }
+}
+
+/// Issues opened vs. closed per day (or week), for burndown/velocity charts. `from`/`to`
+/// filter on `created_at` for the "opened" count and `closed_at` for the "closed" count
+/// independently, so a window like "last 30 days" reports both halves correctly even though
+/// an issue's open and close dates can fall on either side of it.
+pub fn burndown(
+    conn: &Connection,
+    granularity: Granularity,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    priority: Option<&str>,
+    label: Option<&str>,
+) -> Result<Vec<BurndownPoint>> {
+    let format = granularity.strftime_format();
+
+    let mut opened_conditions = Vec::new();
+    let mut opened_params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    push_date_range(&mut opened_conditions, &mut opened_params, "i.created_at", from, to);
+
+    let mut closed_conditions = vec!["i.closed_at IS NOT NULL".to_string()];
+    let mut closed_params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    push_date_range(&mut closed_conditions, &mut closed_params, "i.closed_at", from, to);
+
+    if let Some(priority) = priority {
+        opened_conditions.push("i.priority = ?".to_string());
+        opened_params.push(Box::new(priority.to_string()));
+        closed_conditions.push("i.priority = ?".to_string());
+        closed_params.push(Box::new(priority.to_string()));
+    }
+
+    let join = if label.is_some() { " JOIN labels l ON l.issue_id = i.id" } else { "" };
+    if let Some(label) = label {
+        opened_conditions.push("l.label = ?".to_string());
+        opened_params.push(Box::new(label.to_string()));
+        closed_conditions.push("l.label = ?".to_string());
+        closed_params.push(Box::new(label.to_string()));
+    }
+
+    let opened_where = if opened_conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", opened_conditions.join(" AND "))
+    };
+    let closed_where = format!(" WHERE {}", closed_conditions.join(" AND "));
+
+    let opened_sql = format!(
+        "SELECT strftime('{}', i.created_at) AS bucket, COUNT(*) FROM issues i{}{} GROUP BY bucket",
+        format, join, opened_where
+    );
+    let closed_sql = format!(
+        "SELECT strftime('{}', i.closed_at) AS bucket, COUNT(*) FROM issues i{}{} GROUP BY bucket",
+        format, join, closed_where
+    );
+
+    let mut opened_by_bucket: std::collections::BTreeMap<String, i64> = Default::default();
+    {
+        let mut stmt = conn.prepare(&opened_sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = opened_params.iter().map(|p| p.as_ref()).collect();
+        let rows = stmt
+            .query_map(params_refs.as_slice(), |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        for (bucket, count) in rows {
+            opened_by_bucket.insert(bucket, count);
+        }
+    }
+
+    let mut closed_by_bucket: std::collections::BTreeMap<String, i64> = Default::default();
+    {
+        let mut stmt = conn.prepare(&closed_sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = closed_params.iter().map(|p| p.as_ref()).collect();
+        let rows = stmt
+            .query_map(params_refs.as_slice(), |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        for (bucket, count) in rows {
+            closed_by_bucket.insert(bucket, count);
+        }
+    }
+
+    let mut buckets: Vec<String> = opened_by_bucket.keys().chain(closed_by_bucket.keys()).cloned().collect();
+    buckets.sort();
+    buckets.dedup();
+
+    Ok(buckets
+        .into_iter()
+        .map(|bucket| BurndownPoint {
+            opened: *opened_by_bucket.get(&bucket).unwrap_or(&0),
+            closed: *closed_by_bucket.get(&bucket).unwrap_or(&0),
+            bucket,
+        })
+        .collect())
+}
+
+/// Average time-to-close (`closed_at - created_at`, in seconds), bucketed by priority and
+/// label. An issue with no labels contributes only to the `label: None` bucket for its
+/// priority; a multi-labeled issue contributes once per label it carries.
+pub fn time_to_close(
+    conn: &Connection,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    priority: Option<&str>,
+    label: Option<&str>,
+) -> Result<Vec<TimeToClose>> {
+    let mut conditions = vec!["i.status = 'closed'".to_string(), "i.closed_at IS NOT NULL".to_string()];
+    let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    push_date_range(&mut conditions, &mut params, "i.closed_at", from, to);
+
+    if let Some(priority) = priority {
+        conditions.push("i.priority = ?".to_string());
+        params.push(Box::new(priority.to_string()));
+    }
+    if let Some(label) = label {
+        conditions.push("l.label = ?".to_string());
+        params.push(Box::new(label.to_string()));
+    }
+
+    let sql = format!(
+        "SELECT i.priority, l.label, \
+            AVG((julianday(i.closed_at) - julianday(i.created_at)) * 86400.0), \
+            COUNT(*) \
+         FROM issues i LEFT JOIN labels l ON l.issue_id = i.id \
+         WHERE {} \
+         GROUP BY i.priority, l.label \
+         ORDER BY i.priority, l.label",
+        conditions.join(" AND ")
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let rows = stmt
+        .query_map(params_refs.as_slice(), |row| {
+            Ok(TimeToClose {
+                priority: row.get(0)?,
+                label: row.get(1)?,
+                avg_seconds: row.get(2)?,
+                sample_size: row.get(3)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(rows)
+}
+
+/// Per-issue close time in seconds (`closed_at - created_at`), under the same filters as
+/// `time_to_close`. Unlike `time_to_close`, this returns the raw samples rather than a mean,
+/// so a caller (`commands::stats`) can compute percentiles such as median/p90.
+pub fn cycle_times(
+    conn: &Connection,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    priority: Option<&str>,
+    label: Option<&str>,
+) -> Result<Vec<i64>> {
+    let mut conditions = vec!["i.status = 'closed'".to_string(), "i.closed_at IS NOT NULL".to_string()];
+    let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    push_date_range(&mut conditions, &mut params, "i.closed_at", from, to);
+
+    if let Some(priority) = priority {
+        conditions.push("i.priority = ?".to_string());
+        params.push(Box::new(priority.to_string()));
+    }
+
+    let join = if label.is_some() { " JOIN labels l ON l.issue_id = i.id" } else { "" };
+    if let Some(label) = label {
+        conditions.push("l.label = ?".to_string());
+        params.push(Box::new(label.to_string()));
+    }
+
+    let sql = format!(
+        "SELECT CAST((julianday(i.closed_at) - julianday(i.created_at)) * 86400.0 AS INTEGER) \
+         FROM issues i{} \
+         WHERE {} \
+         ORDER BY i.id",
+        join,
+        conditions.join(" AND ")
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let rows = stmt
+        .query_map(params_refs.as_slice(), |row| row.get::<_, i64>(0))?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(rows)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacklogCount {
+    pub priority: String,
+    pub count: i64,
+}
+
+/// Currently open issues grouped by priority, under the same `priority`/`label` filters as
+/// `time_to_close` (a `priority` filter just collapses this to a single row). Not restricted
+/// by a date range: the open backlog is a point-in-time snapshot, not a historical window.
+pub fn open_backlog_by_priority(
+    conn: &Connection,
+    priority: Option<&str>,
+    label: Option<&str>,
+) -> Result<Vec<BacklogCount>> {
+    let mut conditions = vec!["i.status = 'open'".to_string()];
+    let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+
+    if let Some(priority) = priority {
+        conditions.push("i.priority = ?".to_string());
+        params.push(Box::new(priority.to_string()));
+    }
+
+    let join = if label.is_some() { " JOIN labels l ON l.issue_id = i.id" } else { "" };
+    if let Some(label) = label {
+        conditions.push("l.label = ?".to_string());
+        params.push(Box::new(label.to_string()));
+    }
+
+    let sql = format!(
+        "SELECT i.priority, COUNT(DISTINCT i.id) \
+         FROM issues i{} \
+         WHERE {} \
+         GROUP BY i.priority \
+         ORDER BY i.priority",
+        join,
+        conditions.join(" AND ")
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let rows = stmt
+        .query_map(params_refs.as_slice(), |row| {
+            Ok(BacklogCount {
+                priority: row.get(0)?,
+                count: row.get(1)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(rows)
+}
+
+/// Total tracked time (`time_entries.duration_seconds`) per issue over the window, optionally
+/// restricted to issues carrying `label`.
+pub fn tracked_time_by_issue(
+    conn: &Connection,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    label: Option<&str>,
+) -> Result<Vec<TrackedTime>> {
+    let mut conditions = vec!["t.duration_seconds IS NOT NULL".to_string()];
+    let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    push_date_range(&mut conditions, &mut params, "t.started_at", from, to);
+
+    let join = if label.is_some() { " JOIN labels l ON l.issue_id = t.issue_id" } else { "" };
+    if let Some(label) = label {
+        conditions.push("l.label = ?".to_string());
+        params.push(Box::new(label.to_string()));
+    }
+
+    let sql = format!(
+        "SELECT t.issue_id, SUM(t.duration_seconds) \
+         FROM time_entries t{} \
+         WHERE {} \
+         GROUP BY t.issue_id \
+         ORDER BY t.issue_id",
+        join,
+        conditions.join(" AND ")
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let rows = stmt
+        .query_map(params_refs.as_slice(), |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)))?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(issue_id, total_seconds)| TrackedTime {
+            issue_id,
+            label: label.map(|l| l.to_string()),
+            total_seconds,
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeBucket {
+    pub bucket: String,
+    pub total_seconds: i64,
+}
+
+/// Tracked time (`time_entries.duration_seconds`) bucketed by day or week of `started_at`, for
+/// `chainlink timesheet` -- the time-tracking analogue of `burndown`'s issue-count buckets.
+pub fn tracked_time_by_bucket(
+    conn: &Connection,
+    granularity: Granularity,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> Result<Vec<TimeBucket>> {
+    let format = granularity.strftime_format();
+
+    let mut conditions = vec!["duration_seconds IS NOT NULL".to_string()];
+    let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    push_date_range(&mut conditions, &mut params, "started_at", from, to);
+
+    let sql = format!(
+        "SELECT strftime('{}', started_at) AS bucket, SUM(duration_seconds) \
+         FROM time_entries WHERE {} GROUP BY bucket ORDER BY bucket",
+        format,
+        conditions.join(" AND ")
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let rows = stmt
+        .query_map(params_refs.as_slice(), |row| {
+            Ok(TimeBucket { bucket: row.get(0)?, total_seconds: row.get(1)? })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(rows)
+}
+
+/// Per-session summary: how many distinct issues a session touched (via `time_entries`
+/// started during the session's window) and how much tracked time was logged against them.
+pub fn session_summaries(
+    conn: &Connection,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> Result<Vec<SessionSummary>> {
+    let mut conditions = Vec::new();
+    let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    push_date_range(&mut conditions, &mut params, "s.started_at", from, to);
+
+    let sql = format!(
+        "SELECT s.id, s.started_at, s.ended_at, \
+            COUNT(DISTINCT t.issue_id), \
+            COALESCE(SUM(t.duration_seconds), 0) \
+         FROM sessions s \
+         LEFT JOIN time_entries t \
+            ON t.started_at >= s.started_at \
+            AND (s.ended_at IS NULL OR t.started_at <= s.ended_at) \
+         {} \
+         GROUP BY s.id \
+         ORDER BY s.started_at",
+        if conditions.is_empty() { String::new() } else { format!("WHERE {}", conditions.join(" AND ")) }
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let rows = stmt
+        .query_map(params_refs.as_slice(), |row| {
+            let started_at: String = row.get(1)?;
+            let ended_at: Option<String> = row.get(2)?;
+            Ok(SessionSummary {
+                session_id: row.get(0)?,
+                started_at: crate::db::parse_datetime(started_at),
+                ended_at: ended_at.map(crate::db::parse_datetime),
+                issues_touched: row.get(3)?,
+                time_logged_seconds: row.get(4)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use chrono::TimeZone;
+    use rusqlite::params;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    fn set_issue_dates(db: &Database, id: i64, created_at: &str, closed_at: Option<&str>) {
+        db.conn()
+            .unwrap()
+            .execute(
+                "UPDATE issues SET created_at = ?1, closed_at = ?2, status = CASE WHEN ?2 IS NULL THEN status ELSE 'closed' END WHERE id = ?3",
+                params![created_at, closed_at, id],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_burndown_counts_opened_and_closed_per_day() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        let b = db.create_issue("B", None, "medium").unwrap();
+        set_issue_dates(&db, a, "2024-01-01T00:00:00Z", Some("2024-01-02T00:00:00Z"));
+        set_issue_dates(&db, b, "2024-01-01T00:00:00Z", None);
+
+        let points = burndown(&db.conn().unwrap(), Granularity::Day, None, None, None, None).unwrap();
+        let day1 = points.iter().find(|p| p.bucket == "2024-01-01").unwrap();
+        assert_eq!(day1.opened, 2);
+        assert_eq!(day1.closed, 0);
+        let day2 = points.iter().find(|p| p.bucket == "2024-01-02").unwrap();
+        assert_eq!(day2.opened, 0);
+        assert_eq!(day2.closed, 1);
+    }
+
+    #[test]
+    fn test_burndown_respects_date_range() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        set_issue_dates(&db, a, "2024-01-01T00:00:00Z", None);
+
+        let from = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let points = burndown(&db.conn().unwrap(), Granularity::Day, Some(from), None, None, None).unwrap();
+        assert!(points.is_empty());
+    }
+
+    #[test]
+    fn test_time_to_close_averages_by_priority() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "high").unwrap();
+        let b = db.create_issue("B", None, "high").unwrap();
+        set_issue_dates(&db, a, "2024-01-01T00:00:00Z", Some("2024-01-02T00:00:00Z"));
+        set_issue_dates(&db, b, "2024-01-01T00:00:00Z", Some("2024-01-03T00:00:00Z"));
+
+        let buckets = time_to_close(&db.conn().unwrap(), None, None, None, None).unwrap();
+        let high = buckets.iter().find(|b| b.priority == "high" && b.label.is_none()).unwrap();
+        assert_eq!(high.sample_size, 2);
+        assert!((high.avg_seconds - 129_600.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_cycle_times_returns_raw_samples() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "high").unwrap();
+        let b = db.create_issue("B", None, "high").unwrap();
+        set_issue_dates(&db, a, "2024-01-01T00:00:00Z", Some("2024-01-02T00:00:00Z"));
+        set_issue_dates(&db, b, "2024-01-01T00:00:00Z", Some("2024-01-03T00:00:00Z"));
+
+        let mut samples = cycle_times(&db.conn().unwrap(), None, None, None, None).unwrap();
+        samples.sort();
+        assert_eq!(samples, vec![86_400, 172_800]);
+    }
+
+    #[test]
+    fn test_open_backlog_by_priority_excludes_closed() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "high").unwrap();
+        db.create_issue("B", None, "high").unwrap();
+        db.create_issue("C", None, "low").unwrap();
+        db.close_issue(a).unwrap();
+
+        let counts = open_backlog_by_priority(&db.conn().unwrap(), None, None).unwrap();
+        let high = counts.iter().find(|c| c.priority == "high").unwrap();
+        assert_eq!(high.count, 1);
+        let low = counts.iter().find(|c| c.priority == "low").unwrap();
+        assert_eq!(low.count, 1);
+    }
+
+    #[test]
+    fn test_tracked_time_by_issue_sums_durations() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        db.start_timer(a).unwrap();
+        db.stop_timer(a).unwrap();
+        db.conn()
+            .unwrap()
+            .execute(
+                "UPDATE time_entries SET duration_seconds = 100 WHERE issue_id = ?1",
+                params![a],
+            )
+            .unwrap();
+
+        let totals = tracked_time_by_issue(&db.conn().unwrap(), None, None, None).unwrap();
+        assert_eq!(totals.len(), 1);
+        assert_eq!(totals[0].issue_id, a);
+        assert_eq!(totals[0].total_seconds, 100);
+    }
+
+    #[test]
+    fn test_tracked_time_filters_by_label() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        let b = db.create_issue("B", None, "medium").unwrap();
+        db.add_label(a, "backend").unwrap();
+        for id in [a, b] {
+            db.start_timer(id).unwrap();
+            db.stop_timer(id).unwrap();
+        }
+
+        let totals = tracked_time_by_issue(&db.conn().unwrap(), None, None, Some("backend")).unwrap();
+        assert_eq!(totals.len(), 1);
+        assert_eq!(totals[0].issue_id, a);
+        assert_eq!(totals[0].label, Some("backend".to_string()));
+    }
+
+    #[test]
+    fn test_tracked_time_by_bucket_sums_per_day() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        db.start_timer(a).unwrap();
+        db.stop_timer(a).unwrap();
+        db.conn()
+            .unwrap()
+            .execute(
+                "UPDATE time_entries SET started_at = '2024-01-01T00:00:00Z', duration_seconds = 100 WHERE issue_id = ?1",
+                params![a],
+            )
+            .unwrap();
+
+        let buckets = tracked_time_by_bucket(&db.conn().unwrap(), Granularity::Day, None, None).unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].bucket, "2024-01-01");
+        assert_eq!(buckets[0].total_seconds, 100);
+    }
+
+    #[test]
+    fn test_session_summaries_counts_issues_touched() {
+        let (db, _dir) = setup_test_db();
+        let session_id = db.start_session().unwrap();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        db.set_session_issue(session_id, a).unwrap();
+        db.start_timer(a).unwrap();
+        db.stop_timer(a).unwrap();
+        db.conn()
+            .unwrap()
+            .execute(
+                "UPDATE time_entries SET duration_seconds = 50 WHERE issue_id = ?1",
+                params![a],
+            )
+            .unwrap();
+
+        let summaries = session_summaries(&db.conn().unwrap(), None, None).unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].session_id, session_id);
+        assert_eq!(summaries[0].issues_touched, 1);
+        assert_eq!(summaries[0].time_logged_seconds, 50);
+    }
+}
@@ -0,0 +1,59 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::db::Database;
+
+pub fn export(db: &Database, path: &Path, passphrase: &str) -> Result<()> {
+    db.export_encrypted(path, passphrase)?;
+    println!("Encrypted backup written to {}", path.display());
+    Ok(())
+}
+
+pub fn import(db: &Database, path: &Path, passphrase: &str) -> Result<()> {
+    db.import_encrypted(path, passphrase)?;
+    println!("Restored database from {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_export_then_import_roundtrip() {
+        let (db, dir) = setup_test_db();
+        let id = db.create_issue("Test issue", Some("desc"), "high").unwrap();
+        db.add_label(id, "bug").unwrap();
+        db.add_comment(id, "a comment").unwrap();
+
+        let backup_path = dir.path().join("backup.enc");
+        export(&db, &backup_path, "hunter2").unwrap();
+
+        let (restored, _restored_dir) = setup_test_db();
+        import(&restored, &backup_path, "hunter2").unwrap();
+
+        let issue = restored.get_issue(id).unwrap().unwrap();
+        assert_eq!(issue.title, "Test issue");
+        assert_eq!(restored.get_labels(id).unwrap(), vec!["bug".to_string()]);
+        assert_eq!(restored.get_comments(id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_import_wrong_passphrase_fails() {
+        let (db, dir) = setup_test_db();
+        db.create_issue("Test issue", None, "medium").unwrap();
+        let backup_path = dir.path().join("backup.enc");
+        export(&db, &backup_path, "correct").unwrap();
+
+        let (restored, _restored_dir) = setup_test_db();
+        assert!(import(&restored, &backup_path, "wrong").is_err());
+    }
+}
@@ -0,0 +1,361 @@
+//! Applies a set of heterogeneous mutations to one or more issues inside a single database
+//! transaction: either everything applies cleanly, or a hard database error rolls the whole
+//! group back. Unlike `commands::update` (which bails on the first missing issue), a *soft*
+//! problem with one operation — a missing issue, a dependency cycle — doesn't abort the rest;
+//! it's recorded in the returned report instead, so scripted bulk triage gets a per-operation
+//! answer instead of an all-or-nothing failure.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::io::Read;
+
+use crate::commands::create::validate_priority;
+use crate::db::{self, Database};
+
+/// One mutation `run` can apply, shared by the `--close`/`--delete`/... flags and the `--stdin`
+/// JSON document — both are just different ways of building this list.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Operation {
+    Delete { id: i64 },
+    Close { id: i64 },
+    SetPriority { id: i64, priority: String },
+    AddLabel { id: i64, label: String },
+    RemoveLabel { id: i64, label: String },
+    AddDependency { id: i64, blocker: i64 },
+}
+
+impl Operation {
+    fn describe(&self) -> String {
+        match self {
+            Operation::Delete { id } => format!("delete #{}", id),
+            Operation::Close { id } => format!("close #{}", id),
+            Operation::SetPriority { id, priority } => format!("set-priority #{} = {}", id, priority),
+            Operation::AddLabel { id, label } => format!("label #{} += {}", id, label),
+            Operation::RemoveLabel { id, label } => format!("label #{} -= {}", id, label),
+            Operation::AddDependency { id, blocker } => format!("block #{} on #{}", id, blocker),
+        }
+    }
+}
+
+/// The outcome of a single operation within the batch. `Failed` is reserved for conditions an
+/// operation itself is expected to reject (a dependency cycle, an invalid priority) — anything
+/// else bubbles up as a hard error and rolls the whole transaction back instead of appearing
+/// here.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Success,
+    Skipped(String),
+    Failed(String),
+}
+
+pub struct OperationReport {
+    pub description: String,
+    pub outcome: Outcome,
+}
+
+/// Runs every operation in `ops` inside one transaction and prints a per-operation report.
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    db: &Database,
+    close: &[i64],
+    delete: &[i64],
+    priority: &[String],
+    label: &[String],
+    unlabel: &[String],
+    block_on: &[String],
+    stdin: bool,
+) -> Result<()> {
+    let ops = if stdin {
+        read_operations_from_stdin()?
+    } else {
+        build_operations(close, delete, priority, label, unlabel, block_on)?
+    };
+
+    if ops.is_empty() {
+        println!(
+            "No operations given. Use --close/--delete/--priority/--label/--unlabel/--block-on, \
+             or --stdin with a JSON array of operations."
+        );
+        return Ok(());
+    }
+
+    let reports = apply_all(db, &ops)?;
+
+    for report in &reports {
+        match &report.outcome {
+            Outcome::Success => println!("  ok      {}", report.description),
+            Outcome::Skipped(reason) => println!("  skipped {} ({})", report.description, reason),
+            Outcome::Failed(reason) => println!("  failed  {} ({})", report.description, reason),
+        }
+    }
+
+    let succeeded = reports.iter().filter(|r| r.outcome == Outcome::Success).count();
+    let skipped = reports.iter().filter(|r| matches!(r.outcome, Outcome::Skipped(_))).count();
+    let failed = reports.iter().filter(|r| matches!(r.outcome, Outcome::Failed(_))).count();
+    println!();
+    println!("{} succeeded, {} skipped, {} failed", succeeded, skipped, failed);
+
+    Ok(())
+}
+
+/// Applies every operation inside one transaction, collecting a report for each. Only a hard
+/// database error (propagated via `?` from a helper below) aborts the loop and rolls back —
+/// every soft condition each operation already checks for becomes a report entry instead.
+fn apply_all(db: &Database, ops: &[Operation]) -> Result<Vec<OperationReport>> {
+    db.with_transaction(|conn| {
+        let mut reports = Vec::with_capacity(ops.len());
+        for op in ops {
+            let outcome = apply(conn, op)?;
+            reports.push(OperationReport { description: op.describe(), outcome });
+        }
+        Ok(reports)
+    })
+}
+
+fn apply(conn: &rusqlite::Connection, op: &Operation) -> Result<Outcome> {
+    match op {
+        Operation::Delete { id } => {
+            if db::get_issue_on(conn, *id)?.is_none() {
+                return Ok(Outcome::Skipped("issue not found".to_string()));
+            }
+            Ok(if db::delete_issue_on(conn, *id)? {
+                Outcome::Success
+            } else {
+                Outcome::Skipped("issue not found".to_string())
+            })
+        }
+        Operation::Close { id } => {
+            if db::get_issue_on(conn, *id)?.is_none() {
+                return Ok(Outcome::Skipped("issue not found".to_string()));
+            }
+            Ok(if db::close_issue_on(conn, *id)? {
+                Outcome::Success
+            } else {
+                Outcome::Skipped("issue not found".to_string())
+            })
+        }
+        Operation::SetPriority { id, priority } => {
+            if !validate_priority(priority) {
+                return Ok(Outcome::Failed(format!(
+                    "invalid priority '{}'; must be one of: low, medium, high, critical",
+                    priority
+                )));
+            }
+            if db::get_issue_on(conn, *id)?.is_none() {
+                return Ok(Outcome::Skipped("issue not found".to_string()));
+            }
+            Ok(
+                if db::update_issue_on(conn, *id, None, None, Some(priority.as_str()), None)? {
+                    Outcome::Success
+                } else {
+                    Outcome::Skipped("issue not found".to_string())
+                },
+            )
+        }
+        Operation::AddLabel { id, label } => {
+            if db::get_issue_on(conn, *id)?.is_none() {
+                return Ok(Outcome::Skipped("issue not found".to_string()));
+            }
+            Ok(if db::add_label_on(conn, *id, label)? {
+                Outcome::Success
+            } else {
+                Outcome::Skipped("label already present".to_string())
+            })
+        }
+        Operation::RemoveLabel { id, label } => {
+            if db::get_issue_on(conn, *id)?.is_none() {
+                return Ok(Outcome::Skipped("issue not found".to_string()));
+            }
+            Ok(if db::remove_label_on(conn, *id, label)? {
+                Outcome::Success
+            } else {
+                Outcome::Skipped("label not present".to_string())
+            })
+        }
+        Operation::AddDependency { id, blocker } => {
+            if db::get_issue_on(conn, *id)?.is_none() || db::get_issue_on(conn, *blocker)?.is_none() {
+                return Ok(Outcome::Skipped("issue not found".to_string()));
+            }
+            // Unlike the other operations, a dependency cycle is an expected rejection
+            // (`add_dependency_on` bails rather than returning `Ok(false)`), so it's downgraded
+            // to a report entry here instead of aborting the whole batch. A database error is
+            // still a hard error and rolls the batch back.
+            match db::add_dependency_on(conn, *id, *blocker) {
+                Ok(true) => Ok(Outcome::Success),
+                Ok(false) => Ok(Outcome::Skipped("dependency already present".to_string())),
+                Err(e) if e.downcast_ref::<rusqlite::Error>().is_some() => Err(e),
+                Err(e) => Ok(Outcome::Failed(e.to_string())),
+            }
+        }
+    }
+}
+
+/// Parses `--close`/`--delete`/`--priority`/... flag values into an operation list.
+#[allow(clippy::too_many_arguments)]
+fn build_operations(
+    close: &[i64],
+    delete: &[i64],
+    priority: &[String],
+    label: &[String],
+    unlabel: &[String],
+    block_on: &[String],
+) -> Result<Vec<Operation>> {
+    let mut ops = Vec::new();
+    ops.extend(close.iter().map(|&id| Operation::Close { id }));
+    ops.extend(delete.iter().map(|&id| Operation::Delete { id }));
+
+    for pair in priority {
+        let (id, priority) = parse_id_value(pair, "--priority")?;
+        ops.push(Operation::SetPriority { id, priority });
+    }
+    for pair in label {
+        let (id, label) = parse_id_value(pair, "--label")?;
+        ops.push(Operation::AddLabel { id, label });
+    }
+    for pair in unlabel {
+        let (id, label) = parse_id_value(pair, "--unlabel")?;
+        ops.push(Operation::RemoveLabel { id, label });
+    }
+    for pair in block_on {
+        let (id, blocker) = parse_id_value(pair, "--block-on")?;
+        let blocker: i64 = blocker
+            .parse()
+            .with_context(|| format!("--block-on value '{}' must be ID=BLOCKER_ID", pair))?;
+        ops.push(Operation::AddDependency { id, blocker });
+    }
+
+    Ok(ops)
+}
+
+/// Splits a `ID=VALUE` flag argument (e.g. `9=urgent`) into its two halves.
+fn parse_id_value(pair: &str, flag: &str) -> Result<(i64, String)> {
+    let (id, value) = pair
+        .split_once('=')
+        .with_context(|| format!("{} value '{}' must be ID=VALUE", flag, pair))?;
+    let id: i64 = id
+        .parse()
+        .with_context(|| format!("{} value '{}' must start with a numeric issue ID", flag, pair))?;
+    Ok((id, value.to_string()))
+}
+
+/// Reads a JSON array of operations from stdin, e.g.
+/// `[{"op":"close","id":4},{"op":"add_label","id":9,"label":"urgent"}]`.
+fn read_operations_from_stdin() -> Result<Vec<Operation>> {
+    let mut buf = String::new();
+    std::io::stdin().read_to_string(&mut buf).context("Failed to read operations from stdin")?;
+    serde_json::from_str(&buf).context("Failed to parse operations JSON from stdin")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_apply_all_closes_and_labels_in_one_transaction() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        let b = db.create_issue("B", None, "medium").unwrap();
+
+        let ops = vec![
+            Operation::Close { id: a },
+            Operation::AddLabel { id: b, label: "urgent".to_string() },
+        ];
+        let reports = apply_all(&db, &ops).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert!(reports.iter().all(|r| r.outcome == Outcome::Success));
+
+        assert_eq!(db.get_issue(a).unwrap().unwrap().status, "closed");
+        assert_eq!(db.get_labels(b).unwrap(), vec!["urgent".to_string()]);
+    }
+
+    #[test]
+    fn test_apply_all_reports_missing_issue_without_aborting_rest() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+
+        let ops = vec![Operation::Close { id: 99999 }, Operation::Close { id: a }];
+        let reports = apply_all(&db, &ops).unwrap();
+
+        assert_eq!(reports[0].outcome, Outcome::Skipped("issue not found".to_string()));
+        assert_eq!(reports[1].outcome, Outcome::Success);
+        assert_eq!(db.get_issue(a).unwrap().unwrap().status, "closed");
+    }
+
+    #[test]
+    fn test_apply_all_reports_dependency_cycle_as_failed_not_aborted() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        let b = db.create_issue("B", None, "medium").unwrap();
+        db.add_dependency(a, b).unwrap(); // a blocked by b
+
+        let ops = vec![
+            Operation::AddDependency { id: b, blocker: a }, // would close a cycle
+            Operation::Close { id: a },
+        ];
+        let reports = apply_all(&db, &ops).unwrap();
+
+        assert!(matches!(reports[0].outcome, Outcome::Failed(_)));
+        assert_eq!(reports[1].outcome, Outcome::Success);
+        assert_eq!(db.get_issue(a).unwrap().unwrap().status, "closed");
+    }
+
+    #[test]
+    fn test_apply_all_rejects_invalid_priority_without_touching_db() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+
+        let ops = vec![Operation::SetPriority { id: a, priority: "urgentish".to_string() }];
+        let reports = apply_all(&db, &ops).unwrap();
+
+        assert!(matches!(reports[0].outcome, Outcome::Failed(_)));
+        assert_eq!(db.get_issue(a).unwrap().unwrap().priority, "medium");
+    }
+
+    #[test]
+    fn test_build_operations_parses_all_flag_kinds() {
+        let ops = build_operations(
+            &[4],
+            &[7],
+            &["9=high".to_string()],
+            &["2=urgent".to_string()],
+            &["3=stale".to_string()],
+            &["5=6".to_string()],
+        )
+        .unwrap();
+
+        assert!(matches!(ops[0], Operation::Close { id: 4 }));
+        assert!(matches!(ops[1], Operation::Delete { id: 7 }));
+        assert!(matches!(&ops[2], Operation::SetPriority { id: 9, priority } if priority == "high"));
+        assert!(matches!(&ops[3], Operation::AddLabel { id: 2, label } if label == "urgent"));
+        assert!(matches!(&ops[4], Operation::RemoveLabel { id: 3, label } if label == "stale"));
+        assert!(matches!(ops[5], Operation::AddDependency { id: 5, blocker: 6 }));
+    }
+
+    #[test]
+    fn test_parse_id_value_rejects_missing_equals() {
+        assert!(parse_id_value("9", "--label").is_err());
+    }
+
+    #[test]
+    fn test_parse_id_value_rejects_non_numeric_id() {
+        assert!(parse_id_value("abc=urgent", "--label").is_err());
+    }
+
+    #[test]
+    fn test_read_operations_from_stdin_parses_json_array() {
+        let json = r#"[{"op":"close","id":4},{"op":"add_label","id":9,"label":"urgent"}]"#;
+        let ops: Vec<Operation> = serde_json::from_str(json).unwrap();
+        assert_eq!(ops.len(), 2);
+        assert!(matches!(ops[0], Operation::Close { id: 4 }));
+        assert!(matches!(&ops[1], Operation::AddLabel { id: 9, label } if label == "urgent"));
+    }
+}
@@ -1,14 +1,29 @@
 use anyhow::{bail, Result};
 
+use crate::commands::timer::parse_duration;
 use crate::db::Database;
 
 const VALID_PRIORITIES: [&str; 4] = ["low", "medium", "high", "critical"];
+const VALID_ISSUE_TYPES: [&str; 4] = ["task", "bug", "story", "epic"];
 
 pub fn validate_priority(priority: &str) -> bool {
     VALID_PRIORITIES.contains(&priority)
 }
 
-pub fn run(db: &Database, title: &str, description: Option<&str>, priority: &str) -> Result<()> {
+pub fn validate_issue_type(issue_type: &str) -> bool {
+    VALID_ISSUE_TYPES.contains(&issue_type)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    db: &Database,
+    title: &str,
+    description: Option<&str>,
+    priority: &str,
+    estimate: Option<&str>,
+    issue_type: Option<&str>,
+    epic_id: Option<i64>,
+) -> Result<()> {
     if !validate_priority(priority) {
         bail!(
             "Invalid priority '{}'. Must be one of: {}",
@@ -16,13 +31,47 @@ pub fn run(db: &Database, title: &str, description: Option<&str>, priority: &str
             VALID_PRIORITIES.join(", ")
         );
     }
+    if let Some(issue_type) = issue_type {
+        if !validate_issue_type(issue_type) {
+            bail!(
+                "Invalid issue type '{}'. Must be one of: {}",
+                issue_type,
+                VALID_ISSUE_TYPES.join(", ")
+            );
+        }
+    }
+    if let Some(epic_id) = epic_id {
+        if db.get_issue(epic_id)?.is_none() {
+            bail!("Epic #{} not found", epic_id);
+        }
+    }
+    let estimate_seconds = estimate.map(parse_duration).transpose()?;
 
     let id = db.create_issue(title, description, priority)?;
+    if let Some(seconds) = estimate_seconds {
+        db.set_estimate(id, Some(seconds))?;
+    }
+    if let Some(issue_type) = issue_type {
+        db.set_issue_type(id, issue_type)?;
+    }
+    if let Some(epic_id) = epic_id {
+        db.attach_to_epic(id, Some(epic_id))?;
+    }
     println!("Created issue #{}", id);
     Ok(())
 }
 
-pub fn run_subissue(db: &Database, parent_id: i64, title: &str, description: Option<&str>, priority: &str) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+pub fn run_subissue(
+    db: &Database,
+    parent_id: i64,
+    title: &str,
+    description: Option<&str>,
+    priority: &str,
+    estimate: Option<&str>,
+    issue_type: Option<&str>,
+    epic_id: Option<i64>,
+) -> Result<()> {
     if !validate_priority(priority) {
         bail!(
             "Invalid priority '{}'. Must be one of: {}",
@@ -30,6 +79,21 @@ pub fn run_subissue(db: &Database, parent_id: i64, title: &str, description: Opt
             VALID_PRIORITIES.join(", ")
         );
     }
+    if let Some(issue_type) = issue_type {
+        if !validate_issue_type(issue_type) {
+            bail!(
+                "Invalid issue type '{}'. Must be one of: {}",
+                issue_type,
+                VALID_ISSUE_TYPES.join(", ")
+            );
+        }
+    }
+    if let Some(epic_id) = epic_id {
+        if db.get_issue(epic_id)?.is_none() {
+            bail!("Epic #{} not found", epic_id);
+        }
+    }
+    let estimate_seconds = estimate.map(parse_duration).transpose()?;
 
     // Verify parent exists
     let parent = db.get_issue(parent_id)?;
@@ -38,6 +102,15 @@ pub fn run_subissue(db: &Database, parent_id: i64, title: &str, description: Opt
     }
 
     let id = db.create_subissue(parent_id, title, description, priority)?;
+    if let Some(seconds) = estimate_seconds {
+        db.set_estimate(id, Some(seconds))?;
+    }
+    if let Some(issue_type) = issue_type {
+        db.set_issue_type(id, issue_type)?;
+    }
+    if let Some(epic_id) = epic_id {
+        db.attach_to_epic(id, Some(epic_id))?;
+    }
     println!("Created subissue #{} under #{}", id, parent_id);
     Ok(())
 }
@@ -1,6 +1,8 @@
 use anyhow::{bail, Result};
+use std::collections::BTreeMap;
 
 use crate::db::Database;
+use crate::models::Issue;
 
 pub fn block(db: &Database, issue_id: i64, blocker_id: i64) -> Result<()> {
     // Check if both issues exist
@@ -35,7 +37,7 @@ pub fn unblock(db: &Database, issue_id: i64, blocker_id: i64) -> Result<()> {
     Ok(())
 }
 
-pub fn list_blocked(db: &Database) -> Result<()> {
+pub fn list_blocked(db: &Database, group_by_epic: bool) -> Result<()> {
     let issues = db.list_blocked_issues()?;
 
     if issues.is_empty() {
@@ -44,21 +46,35 @@ pub fn list_blocked(db: &Database) -> Result<()> {
     }
 
     println!("Blocked issues:");
-    for issue in issues {
-        let blockers = db.get_blockers(issue.id)?;
-        let blocker_strs: Vec<String> = blockers.iter().map(|b| format!("#{}", b)).collect();
-        println!(
-            "  #{:<4} {} (blocked by: {})",
-            issue.id,
-            truncate(&issue.title, 40),
-            blocker_strs.join(", ")
-        );
+    if group_by_epic {
+        for_each_epic_group(&issues, |issue| {
+            let blockers = db.get_blockers(issue.id)?;
+            let blocker_strs: Vec<String> = blockers.iter().map(|b| format!("#{}", b)).collect();
+            println!(
+                "  #{:<4} {} (blocked by: {})",
+                issue.id,
+                truncate(&issue.title, 40),
+                blocker_strs.join(", ")
+            );
+            Ok(())
+        })?;
+    } else {
+        for issue in issues {
+            let blockers = db.get_blockers(issue.id)?;
+            let blocker_strs: Vec<String> = blockers.iter().map(|b| format!("#{}", b)).collect();
+            println!(
+                "  #{:<4} {} (blocked by: {})",
+                issue.id,
+                truncate(&issue.title, 40),
+                blocker_strs.join(", ")
+            );
+        }
     }
 
     Ok(())
 }
 
-pub fn list_ready(db: &Database) -> Result<()> {
+pub fn list_ready(db: &Database, group_by_epic: bool) -> Result<()> {
     let issues = db.list_ready_issues()?;
 
     if issues.is_empty() {
@@ -67,8 +83,67 @@ pub fn list_ready(db: &Database) -> Result<()> {
     }
 
     println!("Ready issues (no blockers):");
+    if group_by_epic {
+        for_each_epic_group(&issues, |issue| {
+            println!("  #{:<4} {:8} {}", issue.id, issue.priority, issue.title);
+            Ok(())
+        })?;
+    } else {
+        for issue in issues {
+            println!("  #{:<4} {:8} {}", issue.id, issue.priority, issue.title);
+        }
+    }
+
+    Ok(())
+}
+
+/// Groups `issues` by `epic_id` (issues with no epic last, under "No epic"), printing a header
+/// before each group and calling `f` for every issue in epic order. Used by `list_ready`/
+/// `list_blocked` when `--group-by-epic` is passed.
+fn for_each_epic_group(issues: &[Issue], mut f: impl FnMut(&Issue) -> Result<()>) -> Result<()> {
+    let mut groups: BTreeMap<Option<i64>, Vec<&Issue>> = BTreeMap::new();
     for issue in issues {
-        println!("  #{:<4} {:8} {}", issue.id, issue.priority, issue.title);
+        groups.entry(issue.epic_id).or_default().push(issue);
+    }
+
+    // Issues with no epic print last, under their own header.
+    let (none_group, mut epic_groups): (Vec<_>, Vec<_>) =
+        groups.into_iter().partition(|(epic_id, _)| epic_id.is_none());
+    epic_groups.sort_by_key(|(epic_id, _)| *epic_id);
+
+    for (epic_id, group) in epic_groups {
+        println!("  -- Epic #{} --", epic_id.unwrap());
+        for issue in group {
+            f(issue)?;
+        }
+    }
+    for (_, group) in none_group {
+        println!("  -- No epic --");
+        for issue in group {
+            f(issue)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn schedule(db: &Database) -> Result<()> {
+    let issues = db.list_scheduled_issues()?;
+
+    if issues.is_empty() {
+        println!("No open issues to schedule.");
+        return Ok(());
+    }
+
+    println!("Scheduled execution order (every issue after all its blockers):");
+    for (i, issue) in issues.iter().enumerate() {
+        println!(
+            "  {:<3} #{:<4} {:8} {}",
+            format!("{}.", i + 1),
+            issue.id,
+            issue.priority,
+            issue.title
+        );
     }
 
     Ok(())
@@ -83,3 +158,93 @@ fn truncate(s: &str, max_chars: usize) -> String {
         format!("{}...", truncated)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_direct_cycle_rejected() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        let b = db.create_issue("B", None, "medium").unwrap();
+
+        block(&db, a, b).unwrap(); // a blocked by b
+        let result = block(&db, b, a); // b blocked by a would close the cycle
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_transitive_cycle_rejected() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        let b = db.create_issue("B", None, "medium").unwrap();
+        let c = db.create_issue("C", None, "medium").unwrap();
+
+        block(&db, a, b).unwrap(); // a blocked by b
+        block(&db, b, c).unwrap(); // b blocked by c
+        let result = block(&db, c, a); // c blocked by a: a->b->c->a
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_self_block_rejected() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        let result = block(&db, a, a);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_diamond_dependency_not_rejected() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        let b = db.create_issue("B", None, "medium").unwrap();
+        let c = db.create_issue("C", None, "medium").unwrap();
+        let d = db.create_issue("D", None, "medium").unwrap();
+
+        block(&db, b, a).unwrap(); // b blocked by a
+        block(&db, c, a).unwrap(); // c blocked by a
+        block(&db, d, b).unwrap(); // d blocked by b
+        assert!(block(&db, d, c).is_ok()); // d blocked by c too: still a DAG
+    }
+
+    #[test]
+    fn test_schedule_orders_after_blockers() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "low").unwrap();
+        let b = db.create_issue("B", None, "critical").unwrap();
+        block(&db, b, a).unwrap(); // b blocked by a, despite b being higher priority
+
+        let scheduled = db.list_scheduled_issues().unwrap();
+        let pos_a = scheduled.iter().position(|i| i.id == a).unwrap();
+        let pos_b = scheduled.iter().position(|i| i.id == b).unwrap();
+        assert!(pos_a < pos_b);
+    }
+
+    #[test]
+    fn test_schedule_breaks_ties_by_priority_then_id() {
+        let (db, _dir) = setup_test_db();
+        let low = db.create_issue("Low", None, "low").unwrap();
+        let critical = db.create_issue("Critical", None, "critical").unwrap();
+
+        let scheduled = db.list_scheduled_issues().unwrap();
+        assert_eq!(scheduled[0].id, critical);
+        assert_eq!(scheduled[1].id, low);
+    }
+
+    #[test]
+    fn test_schedule_empty_when_no_open_issues() {
+        let (db, _dir) = setup_test_db();
+        assert!(schedule(&db).is_ok());
+        assert!(db.list_scheduled_issues().unwrap().is_empty());
+    }
+}
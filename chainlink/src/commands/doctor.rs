@@ -0,0 +1,134 @@
+use anyhow::Result;
+
+use crate::db::Database;
+
+pub fn run(db: &Database, fix: bool) -> Result<()> {
+    let report = db.health_check()?;
+
+    if !report.integrity_errors.is_empty() {
+        println!("Integrity check failed:");
+        for err in &report.integrity_errors {
+            println!("  {}", err);
+        }
+    }
+
+    if !report.foreign_key_errors.is_empty() {
+        println!("Foreign key violations:");
+        for err in &report.foreign_key_errors {
+            println!("  {}", err);
+        }
+    }
+
+    println!(
+        "Schema version: {}/{}",
+        report.schema_version, report.schema_total
+    );
+
+    if report.problems.is_empty() {
+        println!("No data invariant violations found.");
+    } else {
+        println!("Data invariant violations:");
+        for problem in &report.problems {
+            match problem.issue_id {
+                Some(id) => println!("  issue #{}: {}", id, problem.problem),
+                None => println!("  {}", problem.problem),
+            }
+        }
+    }
+
+    if fix {
+        let fixed = db.repair()?;
+        if fixed.is_empty() {
+            println!("Nothing to fix.");
+        } else {
+            println!("Repair:");
+            for line in &fixed {
+                println!("  {}", line);
+            }
+        }
+    } else if !report.is_healthy() {
+        println!("Run `chainlink doctor --fix` to repair what can be safely repaired.");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_doctor_clean_database() {
+        let (db, _dir) = setup_test_db();
+        db.create_issue("Test", None, "medium").unwrap();
+
+        let report = db.health_check().unwrap();
+        assert!(report.is_healthy());
+        assert!(run(&db, false).is_ok());
+    }
+
+    #[test]
+    fn test_doctor_detects_invalid_priority() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Test", None, "medium").unwrap();
+        db.conn()
+            .unwrap()
+            .execute(
+                "UPDATE issues SET priority = 'urgent' WHERE id = ?1",
+                [id],
+            )
+            .unwrap();
+
+        let report = db.health_check().unwrap();
+        assert!(!report.is_healthy());
+        assert!(report
+            .problems
+            .iter()
+            .any(|p| p.issue_id == Some(id) && p.problem.contains("invalid priority")));
+    }
+
+    #[test]
+    fn test_doctor_fix_normalizes_priority() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Test", None, "medium").unwrap();
+        db.conn()
+            .unwrap()
+            .execute(
+                "UPDATE issues SET priority = 'urgent' WHERE id = ?1",
+                [id],
+            )
+            .unwrap();
+
+        assert!(run(&db, true).is_ok());
+
+        let issue = db.get_issue(id).unwrap().unwrap();
+        assert_eq!(issue.priority, "medium");
+
+        let report = db.health_check().unwrap();
+        assert!(report.is_healthy());
+    }
+
+    #[test]
+    fn test_doctor_reports_unfixable_status() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Test", None, "medium").unwrap();
+        db.conn()
+            .unwrap()
+            .execute("UPDATE issues SET status = 'in-review' WHERE id = ?1", [id])
+            .unwrap();
+
+        let fixed = db.repair().unwrap();
+        assert!(fixed.iter().any(|line| line.contains("cannot be safely repaired")));
+
+        let issue = db.get_issue(id).unwrap().unwrap();
+        assert_eq!(issue.status, "in-review");
+    }
+}
@@ -0,0 +1,114 @@
+use anyhow::{bail, Result};
+
+use crate::commands::create::validate_priority;
+use crate::db::Database;
+
+/// Creates an issue with `issue_type` set to `"epic"`. Unlike `milestone::create`, an epic is
+/// just an issue: it can be commented on, labeled, and searched like any other.
+pub fn create(db: &Database, title: &str, description: Option<&str>, priority: &str) -> Result<()> {
+    if !validate_priority(priority) {
+        bail!(
+            "Invalid priority '{}'. Must be one of: low, medium, high, critical",
+            priority
+        );
+    }
+
+    let id = db.create_issue(title, description, priority)?;
+    db.set_issue_type(id, "epic")?;
+    println!("Created epic #{}: {}", id, title);
+    Ok(())
+}
+
+/// Shows an epic alongside its children (issues whose `epic_id` points at it) and a rollup of
+/// their status/priority counts.
+pub fn show(db: &Database, id: i64) -> Result<()> {
+    let epic = match db.get_issue(id)? {
+        Some(i) => i,
+        None => bail!("Epic #{} not found", id),
+    };
+    if epic.issue_type != "epic" {
+        bail!("Issue #{} is not an epic", id);
+    }
+
+    println!("Epic #{}: {}", epic.id, epic.title);
+    println!("Status: {}", epic.status);
+
+    let children = db.list_by_epic(id)?;
+    let total = children.len();
+    let closed = children.iter().filter(|i| i.status == "closed").count();
+    println!("\nProgress: {}/{} issues closed", closed, total);
+
+    let mut by_priority: std::collections::BTreeMap<&str, usize> = std::collections::BTreeMap::new();
+    for issue in &children {
+        *by_priority.entry(issue.priority.as_str()).or_default() += 1;
+    }
+    if !by_priority.is_empty() {
+        let counts: Vec<String> = by_priority.iter().map(|(p, n)| format!("{} {}", n, p)).collect();
+        println!("By priority: {}", counts.join(", "));
+    }
+
+    if !children.is_empty() {
+        println!("\nIssues:");
+        for issue in &children {
+            let status_marker = if issue.status == "closed" { "✓" } else { " " };
+            println!(
+                "  #{:<4} [{}] {:8} {}",
+                issue.id, status_marker, issue.priority, issue.title
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_create_epic() {
+        let (db, _dir) = setup_test_db();
+        let result = create(&db, "Big feature", None, "medium");
+        assert!(result.is_ok());
+
+        let issues = db.list_issues(Some("all"), None, None).unwrap();
+        assert_eq!(issues[0].issue_type, "epic");
+    }
+
+    #[test]
+    fn test_show_epic_rolls_up_children() {
+        let (db, _dir) = setup_test_db();
+        create(&db, "Big feature", None, "medium").unwrap();
+        let epic_id = db.list_issues(Some("all"), None, None).unwrap()[0].id;
+
+        let child1 = db.create_issue("Part 1", None, "medium").unwrap();
+        let child2 = db.create_issue("Part 2", None, "medium").unwrap();
+        db.attach_to_epic(child1, Some(epic_id)).unwrap();
+        db.attach_to_epic(child2, Some(epic_id)).unwrap();
+        db.close_issue(child1).unwrap();
+
+        assert!(show(&db, epic_id).is_ok());
+        assert_eq!(db.list_by_epic(epic_id).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_show_nonexistent_epic() {
+        let (db, _dir) = setup_test_db();
+        assert!(show(&db, 99999).is_err());
+    }
+
+    #[test]
+    fn test_show_rejects_non_epic_issue() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Regular issue", None, "medium").unwrap();
+        assert!(show(&db, id).is_err());
+    }
+}
@@ -14,29 +14,90 @@ pub struct ExportedIssue {
     pub status: String,
     pub priority: String,
     pub parent_id: Option<i64>,
+    /// Absent in exports written before estimates existed, so it defaults to `None` rather than
+    /// failing to deserialize them.
+    #[serde(default)]
+    pub estimate_seconds: Option<i64>,
+    /// One of `task`/`bug`/`story`/`epic`. Absent in exports written before issue types existed,
+    /// so it defaults to `"task"` rather than failing to deserialize them.
+    #[serde(default = "default_issue_type")]
+    pub issue_type: String,
+    /// The epic this issue rolls up under, independent of `parent_id`. Absent in exports written
+    /// before epics existed, so it defaults to `None`.
+    #[serde(default)]
+    pub epic_id: Option<i64>,
+    /// Total logged time at export time. Informational only: `import` doesn't recreate
+    /// `time_entries` (this format doesn't carry individual sessions), so this total isn't
+    /// restored anywhere on import.
+    #[serde(default)]
+    pub time_spent_seconds: i64,
     pub labels: Vec<String>,
     pub comments: Vec<ExportedComment>,
+    /// IDs of issues that block this one. Absent in exports written before `import` needed to
+    /// recreate dependencies, so it defaults to empty rather than failing to deserialize them.
+    #[serde(default)]
+    pub blocked_by: Vec<i64>,
+    /// Milestones this issue belongs to. Absent in exports written before milestone membership
+    /// was carried, so it defaults to empty rather than failing to deserialize them.
+    #[serde(default)]
+    pub milestone_ids: Vec<i64>,
     pub created_at: String,
     pub updated_at: String,
     pub closed_at: Option<String>,
 }
 
+fn default_issue_type() -> String {
+    "task".to_string()
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ExportedComment {
     pub content: String,
     pub created_at: String,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct ExportedMilestone {
+    pub id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub status: String,
+    pub created_at: String,
+    pub closed_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedSession {
+    pub id: i64,
+    pub started_at: String,
+    pub ended_at: Option<String>,
+    pub active_issue_id: Option<i64>,
+    pub handoff_notes: Option<String>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ExportData {
     pub version: i32,
     pub exported_at: String,
     pub issues: Vec<ExportedIssue>,
+    /// Absent in exports written before milestones were carried, so it defaults to empty rather
+    /// than failing to deserialize them.
+    #[serde(default)]
+    pub milestones: Vec<ExportedMilestone>,
+    /// Absent in exports written before sessions were carried, so it defaults to empty rather
+    /// than failing to deserialize them.
+    #[serde(default)]
+    pub sessions: Vec<ExportedSession>,
 }
 
-fn export_issue(db: &Database, issue: &Issue) -> Result<ExportedIssue> {
+/// Builds the `ExportedIssue` JSON shape for a single issue. Shared with `daemon::router`, so
+/// the admin API returns the exact same representation as `chainlink export --format json`.
+pub(crate) fn export_issue(db: &Database, issue: &Issue) -> Result<ExportedIssue> {
     let labels = db.get_labels(issue.id)?;
     let comments = db.get_comments(issue.id)?;
+    let blocked_by = db.get_blockers(issue.id)?;
+    let milestone_ids = db.get_issue_milestones(issue.id)?;
+    let time_spent_seconds = db.get_total_time(issue.id)?;
 
     Ok(ExportedIssue {
         id: issue.id,
@@ -45,6 +106,10 @@ fn export_issue(db: &Database, issue: &Issue) -> Result<ExportedIssue> {
         status: issue.status.clone(),
         priority: issue.priority.clone(),
         parent_id: issue.parent_id,
+        estimate_seconds: issue.estimate_seconds,
+        issue_type: issue.issue_type.clone(),
+        epic_id: issue.epic_id,
+        time_spent_seconds,
         labels,
         comments: comments
             .into_iter()
@@ -53,6 +118,8 @@ fn export_issue(db: &Database, issue: &Issue) -> Result<ExportedIssue> {
                 created_at: c.created_at.to_rfc3339(),
             })
             .collect(),
+        blocked_by,
+        milestone_ids,
         created_at: issue.created_at.to_rfc3339(),
         updated_at: issue.updated_at.to_rfc3339(),
         closed_at: issue.closed_at.map(|dt| dt.to_rfc3339()),
@@ -67,10 +134,37 @@ pub fn run_json(db: &Database, output_path: Option<&str>) -> Result<()> {
         .map(|i| export_issue(db, i))
         .collect::<Result<Vec<_>>>()?;
 
+    let milestones = db
+        .list_milestones(None)?
+        .into_iter()
+        .map(|m| ExportedMilestone {
+            id: m.id,
+            name: m.name,
+            description: m.description,
+            status: m.status,
+            created_at: m.created_at.to_rfc3339(),
+            closed_at: m.closed_at.map(|dt| dt.to_rfc3339()),
+        })
+        .collect();
+
+    let sessions = db
+        .list_sessions()?
+        .into_iter()
+        .map(|s| ExportedSession {
+            id: s.id,
+            started_at: s.started_at.to_rfc3339(),
+            ended_at: s.ended_at.map(|dt| dt.to_rfc3339()),
+            active_issue_id: s.active_issue_id,
+            handoff_notes: s.handoff_notes,
+        })
+        .collect();
+
     let data = ExportData {
-        version: 1,
+        version: 2,
         exported_at: chrono::Utc::now().to_rfc3339(),
         issues: exported,
+        milestones,
+        sessions,
     };
 
     let json = serde_json::to_string_pretty(&data)?;
@@ -116,6 +210,30 @@ pub fn run_markdown(db: &Database, output_path: Option<&str>) -> Result<()> {
         }
     }
 
+    let epics: Vec<_> = issues.iter().filter(|i| i.issue_type == "epic").collect();
+    if !epics.is_empty() {
+        md.push_str("## Epics\n\n");
+        for epic in &epics {
+            let children = db.list_by_epic(epic.id)?;
+            let open_count = children.iter().filter(|c| c.status != "closed").count();
+            md.push_str(&format!(
+                "### #{}: {} ({}/{} open)\n\n",
+                epic.id,
+                epic.title,
+                open_count,
+                children.len()
+            ));
+            for child in &children {
+                let checkbox = if child.status == "closed" { "[x]" } else { "[ ]" };
+                md.push_str(&format!(
+                    "- {} #{}: {} ({})\n",
+                    checkbox, child.id, child.title, child.priority
+                ));
+            }
+            md.push('\n');
+        }
+    }
+
     match output_path {
         Some(path) => {
             fs::write(path, md).context("Failed to write export file")?;
@@ -147,6 +265,23 @@ fn write_issue_md(md: &mut String, db: &Database, issue: &Issue) -> Result<()> {
         md.push_str(&format!("- **Parent:** #{}\n", parent_id));
     }
 
+    if let Some(epic_id) = issue.epic_id {
+        md.push_str(&format!("- **Epic:** #{}\n", epic_id));
+    }
+
+    let time_spent_seconds = db.get_total_time(issue.id)?;
+    if issue.estimate_seconds.is_some() || time_spent_seconds > 0 {
+        let estimate = issue
+            .estimate_seconds
+            .map(format_duration)
+            .unwrap_or_else(|| "none".to_string());
+        md.push_str(&format!(
+            "- **Estimate/Spent:** {} / {}\n",
+            estimate,
+            format_duration(time_spent_seconds)
+        ));
+    }
+
     let labels = db.get_labels(issue.id)?;
     if !labels.is_empty() {
         md.push_str(&format!("- **Labels:** {}\n", labels.join(", ")));
@@ -179,6 +314,16 @@ fn write_issue_md(md: &mut String, db: &Database, issue: &Issue) -> Result<()> {
     Ok(())
 }
 
+fn format_duration(seconds: i64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    match (hours, minutes) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h{}m", h, m),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,6 +360,40 @@ mod tests {
         assert_eq!(exported.labels.len(), 2);
     }
 
+    #[test]
+    fn test_export_issue_with_blockers() {
+        let (db, _dir) = setup_test_db();
+        let blocker_id = db.create_issue("Blocker", None, "medium").unwrap();
+        let blocked_id = db.create_issue("Blocked", None, "medium").unwrap();
+        db.add_dependency(blocked_id, blocker_id).unwrap();
+        let issue = db.get_issue(blocked_id).unwrap().unwrap();
+        let exported = export_issue(&db, &issue).unwrap();
+        assert_eq!(exported.blocked_by, vec![blocker_id]);
+    }
+
+    #[test]
+    fn test_export_issue_with_milestone() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Test issue", None, "medium").unwrap();
+        let milestone_id = db.create_milestone("v1.0", None).unwrap();
+        db.add_issue_to_milestone(milestone_id, id).unwrap();
+        let issue = db.get_issue(id).unwrap().unwrap();
+        let exported = export_issue(&db, &issue).unwrap();
+        assert_eq!(exported.milestone_ids, vec![milestone_id]);
+    }
+
+    #[test]
+    fn test_export_issue_with_estimate_and_time_spent() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Test issue", None, "medium").unwrap();
+        db.set_estimate(id, Some(7200)).unwrap();
+        db.log_time(id, 3600).unwrap();
+        let issue = db.get_issue(id).unwrap().unwrap();
+        let exported = export_issue(&db, &issue).unwrap();
+        assert_eq!(exported.estimate_seconds, Some(7200));
+        assert_eq!(exported.time_spent_seconds, 3600);
+    }
+
     #[test]
     fn test_export_issue_with_comments() {
         let (db, _dir) = setup_test_db();
@@ -247,10 +426,25 @@ mod tests {
         assert!(result.is_ok());
         let content = fs::read_to_string(&output_path).unwrap();
         let data: ExportData = serde_json::from_str(&content).unwrap();
-        assert_eq!(data.version, 1);
+        assert_eq!(data.version, 2);
         assert_eq!(data.issues.len(), 2);
     }
 
+    #[test]
+    fn test_run_json_includes_milestones_and_sessions() {
+        let (db, dir) = setup_test_db();
+        db.create_milestone("v1.0", None).unwrap();
+        let session_id = db.start_session().unwrap();
+        db.end_session(session_id, Some("handed off")).unwrap();
+        let output_path = dir.path().join("export.json");
+        run_json(&db, Some(output_path.to_str().unwrap())).unwrap();
+        let content = fs::read_to_string(&output_path).unwrap();
+        let data: ExportData = serde_json::from_str(&content).unwrap();
+        assert_eq!(data.milestones.len(), 1);
+        assert_eq!(data.sessions.len(), 1);
+        assert_eq!(data.sessions[0].handoff_notes.as_deref(), Some("handed off"));
+    }
+
     #[test]
     fn test_run_json_empty_database() {
         let (db, dir) = setup_test_db();
@@ -310,15 +504,23 @@ mod tests {
                 status: "open".to_string(),
                 priority: "medium".to_string(),
                 parent_id: None,
+                estimate_seconds: Some(3600),
+                issue_type: "task".to_string(),
+                epic_id: None,
+                time_spent_seconds: 0,
                 labels: vec!["bug".to_string()],
                 comments: vec![ExportedComment {
                     content: "Comment".to_string(),
                     created_at: "2024-01-01T00:00:00Z".to_string(),
                 }],
+                blocked_by: vec![],
+                milestone_ids: vec![],
                 created_at: "2024-01-01T00:00:00Z".to_string(),
                 updated_at: "2024-01-01T00:00:00Z".to_string(),
                 closed_at: None,
             }],
+            milestones: vec![],
+            sessions: vec![],
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: ExportData = serde_json::from_str(&json).unwrap();
@@ -326,6 +528,14 @@ mod tests {
         assert_eq!(parsed.issues.len(), 1);
     }
 
+    #[test]
+    fn test_export_data_defaults_missing_milestones_and_sessions() {
+        let json = r#"{"version":1,"exported_at":"2024-01-01T00:00:00Z","issues":[]}"#;
+        let data: ExportData = serde_json::from_str(json).unwrap();
+        assert!(data.milestones.is_empty());
+        assert!(data.sessions.is_empty());
+    }
+
     proptest! {
         #[test]
         fn prop_export_never_panics(title in "[a-zA-Z0-9 ]{1,50}") {
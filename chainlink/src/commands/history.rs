@@ -0,0 +1,125 @@
+use anyhow::{bail, Result};
+
+use crate::db::Database;
+
+pub fn list(db: &Database, issue_id: i64) -> Result<()> {
+    if db.get_issue(issue_id)?.is_none() {
+        bail!("Issue #{} not found", issue_id);
+    }
+
+    let entries = db.get_issue_history(issue_id)?;
+    if entries.is_empty() {
+        println!("No history for issue #{}.", issue_id);
+        return Ok(());
+    }
+
+    println!("History for issue #{}:", issue_id);
+    for entry in entries {
+        let marker = if entry.reverted { " (reverted)" } else { "" };
+        println!(
+            "  [{}] {}: {:?} -> {:?}{}",
+            entry.changed_at.format("%Y-%m-%d %H:%M:%S"),
+            entry.field,
+            entry.old_value,
+            entry.new_value,
+            marker
+        );
+    }
+
+    Ok(())
+}
+
+pub fn undo(db: &Database, issue_id: i64) -> Result<()> {
+    if db.get_issue(issue_id)?.is_none() {
+        bail!("Issue #{} not found", issue_id);
+    }
+
+    let reverted = db.undo_last_change(issue_id)?;
+    if reverted.is_empty() {
+        println!("Nothing to undo for issue #{}.", issue_id);
+    } else {
+        println!(
+            "Reverted {} on issue #{}: {}",
+            if reverted.len() == 1 { "change" } else { "changes" },
+            issue_id,
+            reverted.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_undo_reverts_title() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Original", None, "medium").unwrap();
+        db.update_issue(id, Some("Changed"), None, None, None).unwrap();
+
+        let result = undo(&db, id);
+        assert!(result.is_ok());
+
+        let issue = db.get_issue(id).unwrap().unwrap();
+        assert_eq!(issue.title, "Original");
+    }
+
+    #[test]
+    fn test_undo_with_no_history() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Original", None, "medium").unwrap();
+
+        let result = undo(&db, id);
+        assert!(result.is_ok());
+
+        let issue = db.get_issue(id).unwrap().unwrap();
+        assert_eq!(issue.title, "Original");
+    }
+
+    #[test]
+    fn test_undo_only_reverts_most_recent_change() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Original", None, "medium").unwrap();
+        db.update_issue(id, Some("First edit"), None, None, None).unwrap();
+        db.update_issue(id, Some("Second edit"), None, None, None).unwrap();
+
+        undo(&db, id).unwrap();
+        let issue = db.get_issue(id).unwrap().unwrap();
+        assert_eq!(issue.title, "First edit");
+
+        undo(&db, id).unwrap();
+        let issue = db.get_issue(id).unwrap().unwrap();
+        assert_eq!(issue.title, "Original");
+    }
+
+    #[test]
+    fn test_undo_nonexistent_issue() {
+        let (db, _dir) = setup_test_db();
+        let result = undo(&db, 99999);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_history_lists_changes() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Original", None, "medium").unwrap();
+        db.update_issue(id, Some("Changed"), None, None, None).unwrap();
+
+        let result = list(&db, id);
+        assert!(result.is_ok());
+
+        let entries = db.get_issue_history(id).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].field, "title");
+    }
+}
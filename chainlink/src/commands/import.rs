@@ -0,0 +1,577 @@
+use anyhow::{bail, Context, Result};
+use rusqlite::Connection;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::commands::export::ExportData;
+use crate::db::{self, Database};
+
+const ID_STRATEGIES: [&str; 3] = ["preserve", "remap", "merge"];
+
+pub fn validate_id_strategy(strategy: &str) -> bool {
+    ID_STRATEGIES.contains(&strategy)
+}
+
+/// Restores issues, labels, comments, parent links, and block dependencies from a JSON export
+/// produced by `export`. Runs as a single transaction, so a malformed record rolls back every
+/// row the import would otherwise have written.
+///
+/// `id_strategy` is `"preserve"` (keep the ids from the export, failing if any collide with an
+/// existing issue), `"remap"` (assign fresh ids and translate `parent_id`/`blocked_by`/milestone
+/// references to match), or `"merge"` (fold a divergent export into a database that already has
+/// some of the same issues, resolving conflicts with `merge_into`).
+pub fn run(db: &Database, path: &Path, id_strategy: &str) -> Result<()> {
+    if !validate_id_strategy(id_strategy) {
+        bail!(
+            "Invalid id strategy '{}'. Must be one of: {}",
+            id_strategy,
+            ID_STRATEGIES.join(", ")
+        );
+    }
+
+    let json = fs::read_to_string(path).context("Failed to read import file")?;
+    let data: ExportData =
+        serde_json::from_str(&json).context("File does not contain a valid chainlink export")?;
+
+    if id_strategy == "merge" {
+        let summary = db.with_transaction(|conn| merge_into(conn, &data))?;
+        println!("{}", summary.describe(path));
+        return Ok(());
+    }
+
+    let remap = id_strategy == "remap";
+    let issue_count = data.issues.len();
+
+    db.with_transaction(|conn| {
+        let mut id_map: HashMap<i64, i64> = HashMap::new();
+        let mut milestone_id_map: HashMap<i64, i64> = HashMap::new();
+
+        for milestone in &data.milestones {
+            let new_id = db::insert_imported_milestone_on(
+                conn,
+                if remap { None } else { Some(milestone.id) },
+                &milestone.name,
+                milestone.description.as_deref(),
+                &milestone.status,
+                &milestone.created_at,
+                milestone.closed_at.as_deref(),
+            )?;
+            milestone_id_map.insert(milestone.id, new_id);
+        }
+
+        for issue in &data.issues {
+            let new_id = db::insert_imported_issue_on(
+                conn,
+                if remap { None } else { Some(issue.id) },
+                &issue.title,
+                issue.description.as_deref(),
+                &issue.status,
+                &issue.priority,
+                issue.estimate_seconds,
+                &issue.issue_type,
+                &issue.created_at,
+                &issue.updated_at,
+                issue.closed_at.as_deref(),
+            )?;
+            id_map.insert(issue.id, new_id);
+        }
+
+        for issue in &data.issues {
+            let Some(parent_id) = issue.parent_id else {
+                continue;
+            };
+            let mapped_parent = *id_map.get(&parent_id).with_context(|| {
+                format!(
+                    "Issue #{} has parent #{} which is not present in the export",
+                    issue.id, parent_id
+                )
+            })?;
+            db::set_imported_parent_on(conn, id_map[&issue.id], mapped_parent)?;
+        }
+
+        for issue in &data.issues {
+            let Some(epic_id) = issue.epic_id else {
+                continue;
+            };
+            let mapped_epic = *id_map.get(&epic_id).with_context(|| {
+                format!(
+                    "Issue #{} has epic #{} which is not present in the export",
+                    issue.id, epic_id
+                )
+            })?;
+            db::set_imported_epic_on(conn, id_map[&issue.id], mapped_epic)?;
+        }
+
+        for issue in &data.issues {
+            let new_id = id_map[&issue.id];
+            for label in &issue.labels {
+                db::insert_imported_label_on(conn, new_id, label)?;
+            }
+            for comment in &issue.comments {
+                db::insert_imported_comment_on(conn, new_id, &comment.content, &comment.created_at)?;
+            }
+            for blocker_id in &issue.blocked_by {
+                let mapped_blocker = *id_map.get(blocker_id).with_context(|| {
+                    format!(
+                        "Issue #{} is blocked by #{} which is not present in the export",
+                        issue.id, blocker_id
+                    )
+                })?;
+                db::insert_imported_dependency_on(conn, mapped_blocker, new_id)?;
+            }
+            for milestone_id in &issue.milestone_ids {
+                let mapped_milestone = *milestone_id_map.get(milestone_id).with_context(|| {
+                    format!(
+                        "Issue #{} belongs to milestone #{} which is not present in the export",
+                        issue.id, milestone_id
+                    )
+                })?;
+                db::set_imported_issue_milestone_on(conn, new_id, mapped_milestone)?;
+            }
+        }
+
+        for session in &data.sessions {
+            let active_issue_id = session
+                .active_issue_id
+                .map(|id| id_map.get(&id).copied().unwrap_or(id));
+            db::insert_imported_session_on(
+                conn,
+                if remap { None } else { Some(session.id) },
+                &session.started_at,
+                session.ended_at.as_deref(),
+                active_issue_id,
+                session.handoff_notes.as_deref(),
+            )?;
+        }
+
+        Ok(())
+    })?;
+
+    println!("Imported {} issues from {}", issue_count, path.display());
+    Ok(())
+}
+
+/// Tallies what `merge_into` did, so `run` can print an auditable summary of what a merge
+/// actually changed instead of a bare "done".
+#[derive(Debug, Default)]
+pub struct MergeSummary {
+    pub issues_added: usize,
+    pub issues_updated: usize,
+    pub issues_unchanged: usize,
+    pub milestones_added: usize,
+    pub milestone_links_added: usize,
+    pub sessions_added: usize,
+    pub labels_added: usize,
+    pub comments_added: usize,
+    pub comments_skipped: usize,
+    pub dependencies_added: usize,
+}
+
+impl MergeSummary {
+    fn describe(&self, path: &Path) -> String {
+        format!(
+            "Merged {}: {} issue(s) added, {} updated, {} unchanged; {} milestone(s) added ({} link(s)); \
+             {} session(s) added; {} label(s) added; {} comment(s) added ({} already present); \
+             {} dependency link(s) added",
+            path.display(),
+            self.issues_added,
+            self.issues_updated,
+            self.issues_unchanged,
+            self.milestones_added,
+            self.milestone_links_added,
+            self.sessions_added,
+            self.labels_added,
+            self.comments_added,
+            self.comments_skipped,
+            self.dependencies_added,
+        )
+    }
+}
+
+/// Folds `data` into the database already open on `conn`, for two stores that have diverged
+/// independently (e.g. offline copies of the same project) rather than one restoring a pristine
+/// export into an empty database.
+///
+/// An issue id is treated as the same logical issue on both sides only when its `created_at`
+/// also matches (an issue's `created_at` never changes after creation, so two rows sharing it
+/// can only be copies of each other). When it does, conflicts are resolved last-writer-wins on
+/// `updated_at`: the newer side's fields win, the older side is left untouched. When an id
+/// collides but `created_at` differs, the two rows are unrelated issues that independently landed
+/// on the same autoincremented id — the incoming one is remapped to a fresh id instead, the same
+/// way `"remap"` avoids collisions on a cold import. Either way, append-only data (labels,
+/// comments, dependency edges, milestone links) is unioned rather than overwritten, so nothing
+/// recorded by either side is lost.
+fn merge_into(conn: &Connection, data: &ExportData) -> Result<MergeSummary> {
+    let mut summary = MergeSummary::default();
+    let mut id_map: HashMap<i64, i64> = HashMap::new();
+    let mut milestone_id_map: HashMap<i64, i64> = HashMap::new();
+
+    for milestone in &data.milestones {
+        if db::milestone_id_taken_on(conn, milestone.id)? {
+            // No `updated_at` to arbitrate with, so the existing milestone (already merged by an
+            // earlier sync, or simply created independently under the same id) wins as-is.
+            milestone_id_map.insert(milestone.id, milestone.id);
+            continue;
+        }
+        let new_id = db::insert_imported_milestone_on(
+            conn,
+            Some(milestone.id),
+            &milestone.name,
+            milestone.description.as_deref(),
+            &milestone.status,
+            &milestone.created_at,
+            milestone.closed_at.as_deref(),
+        )?;
+        milestone_id_map.insert(milestone.id, new_id);
+        summary.milestones_added += 1;
+    }
+
+    for issue in &data.issues {
+        let existing = db::get_issue_on(conn, issue.id)?;
+        match existing {
+            None => {
+                db::insert_imported_issue_on(
+                    conn,
+                    Some(issue.id),
+                    &issue.title,
+                    issue.description.as_deref(),
+                    &issue.status,
+                    &issue.priority,
+                    issue.estimate_seconds,
+                    &issue.issue_type,
+                    &issue.created_at,
+                    &issue.updated_at,
+                    issue.closed_at.as_deref(),
+                )?;
+                id_map.insert(issue.id, issue.id);
+                summary.issues_added += 1;
+            }
+            Some(existing) if existing.created_at.to_rfc3339() == issue.created_at => {
+                if issue.updated_at > existing.updated_at.to_rfc3339() {
+                    db::overwrite_merged_issue_on(
+                        conn,
+                        issue.id,
+                        &issue.title,
+                        issue.description.as_deref(),
+                        &issue.status,
+                        &issue.priority,
+                        issue.estimate_seconds,
+                        &issue.issue_type,
+                        &issue.created_at,
+                        &issue.updated_at,
+                        issue.closed_at.as_deref(),
+                    )?;
+                    summary.issues_updated += 1;
+                } else {
+                    summary.issues_unchanged += 1;
+                }
+                id_map.insert(issue.id, issue.id);
+            }
+            Some(_) => {
+                // Same id, unrelated issue: keep the local row untouched and give the incoming
+                // one a fresh id instead of clobbering it.
+                let new_id = db::insert_imported_issue_on(
+                    conn,
+                    None,
+                    &issue.title,
+                    issue.description.as_deref(),
+                    &issue.status,
+                    &issue.priority,
+                    issue.estimate_seconds,
+                    &issue.issue_type,
+                    &issue.created_at,
+                    &issue.updated_at,
+                    issue.closed_at.as_deref(),
+                )?;
+                id_map.insert(issue.id, new_id);
+                summary.issues_added += 1;
+            }
+        }
+    }
+
+    for issue in &data.issues {
+        let Some(parent_id) = issue.parent_id else {
+            continue;
+        };
+        if let Some(&mapped_parent) = id_map.get(&parent_id) {
+            db::set_imported_parent_on(conn, id_map[&issue.id], mapped_parent)?;
+        }
+    }
+
+    for issue in &data.issues {
+        let Some(epic_id) = issue.epic_id else {
+            continue;
+        };
+        if let Some(&mapped_epic) = id_map.get(&epic_id) {
+            db::set_imported_epic_on(conn, id_map[&issue.id], mapped_epic)?;
+        }
+    }
+
+    for issue in &data.issues {
+        let new_id = id_map[&issue.id];
+
+        for label in &issue.labels {
+            db::insert_imported_label_on(conn, new_id, label)?;
+            if conn.changes() > 0 {
+                summary.labels_added += 1;
+            }
+        }
+
+        for comment in &issue.comments {
+            if db::comment_exists_on(conn, new_id, &comment.content, &comment.created_at)? {
+                summary.comments_skipped += 1;
+                continue;
+            }
+            db::insert_imported_comment_on(conn, new_id, &comment.content, &comment.created_at)?;
+            summary.comments_added += 1;
+        }
+
+        for blocker_id in &issue.blocked_by {
+            let Some(&mapped_blocker) = id_map.get(blocker_id) else {
+                continue;
+            };
+            db::insert_imported_dependency_on(conn, mapped_blocker, new_id)?;
+            if conn.changes() > 0 {
+                summary.dependencies_added += 1;
+            }
+        }
+
+        for milestone_id in &issue.milestone_ids {
+            let Some(&mapped_milestone) = milestone_id_map.get(milestone_id) else {
+                continue;
+            };
+            db::set_imported_issue_milestone_on(conn, new_id, mapped_milestone)?;
+            if conn.changes() > 0 {
+                summary.milestone_links_added += 1;
+            }
+        }
+    }
+
+    for session in &data.sessions {
+        if db::session_id_taken_on(conn, session.id)? {
+            continue;
+        }
+        let active_issue_id = session
+            .active_issue_id
+            .map(|id| id_map.get(&id).copied().unwrap_or(id));
+        db::insert_imported_session_on(
+            conn,
+            Some(session.id),
+            &session.started_at,
+            session.ended_at.as_deref(),
+            active_issue_id,
+            session.handoff_notes.as_deref(),
+        )?;
+        summary.sessions_added += 1;
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::export;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_import_preserve_roundtrip() {
+        let (db, dir) = setup_test_db();
+        let parent_id = db.create_issue("Parent", None, "medium").unwrap();
+        let child_id = db.create_subissue(parent_id, "Child", Some("desc"), "high").unwrap();
+        db.add_label(child_id, "bug").unwrap();
+        db.add_comment(child_id, "a comment").unwrap();
+        db.add_dependency(child_id, parent_id).unwrap();
+
+        let export_path = dir.path().join("export.json");
+        export::run_json(&db, Some(export_path.to_str().unwrap())).unwrap();
+
+        let (restored, _restored_dir) = setup_test_db();
+        run(&restored, &export_path, "preserve").unwrap();
+
+        let child = restored.get_issue(child_id).unwrap().unwrap();
+        assert_eq!(child.title, "Child");
+        assert_eq!(child.parent_id, Some(parent_id));
+        assert_eq!(restored.get_labels(child_id).unwrap(), vec!["bug".to_string()]);
+        assert_eq!(restored.get_comments(child_id).unwrap().len(), 1);
+        assert_eq!(restored.get_blockers(child_id).unwrap(), vec![parent_id]);
+    }
+
+    #[test]
+    fn test_import_preserve_rejects_id_collision() {
+        let (db, dir) = setup_test_db();
+        let id = db.create_issue("Original", None, "medium").unwrap();
+        let export_path = dir.path().join("export.json");
+        export::run_json(&db, Some(export_path.to_str().unwrap())).unwrap();
+
+        db.create_issue("Other", None, "medium").unwrap();
+        assert_eq!(id, 1);
+        assert!(run(&db, &export_path, "preserve").is_err());
+    }
+
+    #[test]
+    fn test_import_remap_assigns_fresh_ids_and_keeps_links() {
+        let (db, dir) = setup_test_db();
+        let parent_id = db.create_issue("Parent", None, "medium").unwrap();
+        let child_id = db.create_subissue(parent_id, "Child", None, "medium").unwrap();
+        db.add_dependency(child_id, parent_id).unwrap();
+
+        let export_path = dir.path().join("export.json");
+        export::run_json(&db, Some(export_path.to_str().unwrap())).unwrap();
+
+        let (restored, _restored_dir) = setup_test_db();
+        let existing_id = restored.create_issue("Existing", None, "low").unwrap();
+        run(&restored, &export_path, "remap").unwrap();
+
+        let issues = restored.list_issues(Some("all"), None, None).unwrap();
+        assert_eq!(issues.len(), 3);
+
+        let new_child = issues
+            .iter()
+            .find(|i| i.title == "Child")
+            .expect("imported child issue");
+        let new_parent = issues
+            .iter()
+            .find(|i| i.title == "Parent")
+            .expect("imported parent issue");
+        assert_ne!(new_parent.id, existing_id);
+        assert_eq!(new_child.parent_id, Some(new_parent.id));
+        assert_eq!(
+            restored.get_blockers(new_child.id).unwrap(),
+            vec![new_parent.id]
+        );
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_id_strategy() {
+        let (db, dir) = setup_test_db();
+        db.create_issue("Test issue", None, "medium").unwrap();
+        let export_path = dir.path().join("export.json");
+        export::run_json(&db, Some(export_path.to_str().unwrap())).unwrap();
+
+        let (restored, _restored_dir) = setup_test_db();
+        assert!(run(&restored, &export_path, "bogus").is_err());
+    }
+
+    #[test]
+    fn test_import_missing_file_fails() {
+        let (db, dir) = setup_test_db();
+        let missing_path = dir.path().join("does-not-exist.json");
+        assert!(run(&db, &missing_path, "preserve").is_err());
+    }
+
+    #[test]
+    fn test_import_preserve_carries_milestone_membership() {
+        let (db, dir) = setup_test_db();
+        let id = db.create_issue("Test issue", None, "medium").unwrap();
+        let milestone_id = db.create_milestone("v1.0", None).unwrap();
+        db.add_issue_to_milestone(milestone_id, id).unwrap();
+
+        let export_path = dir.path().join("export.json");
+        export::run_json(&db, Some(export_path.to_str().unwrap())).unwrap();
+
+        let (restored, _restored_dir) = setup_test_db();
+        run(&restored, &export_path, "preserve").unwrap();
+        assert_eq!(restored.get_issue_milestones(id).unwrap(), vec![milestone_id]);
+    }
+
+    #[test]
+    fn test_merge_adds_issues_missing_locally() {
+        let (remote, dir) = setup_test_db();
+        remote.create_issue("Only on remote", None, "medium").unwrap();
+        let export_path = dir.path().join("export.json");
+        export::run_json(&remote, Some(export_path.to_str().unwrap())).unwrap();
+
+        let (local, _local_dir) = setup_test_db();
+        run(&local, &export_path, "merge").unwrap();
+
+        let issues = local.list_issues(Some("all"), None, None).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].title, "Only on remote");
+    }
+
+    #[test]
+    fn test_merge_last_writer_wins_on_newer_updated_at() {
+        let (remote, dir) = setup_test_db();
+        let id = remote.create_issue("Shared", None, "medium").unwrap();
+        let export_path = dir.path().join("export.json");
+        export::run_json(&remote, Some(export_path.to_str().unwrap())).unwrap();
+
+        // Sync once so both sides agree the row is the same issue (same id, same created_at).
+        let (local, _local_dir) = setup_test_db();
+        run(&local, &export_path, "merge").unwrap();
+
+        remote.update_issue(id, Some("Renamed by remote"), None, None, None).unwrap();
+        export::run_json(&remote, Some(export_path.to_str().unwrap())).unwrap();
+        run(&local, &export_path, "merge").unwrap();
+
+        let issue = local.get_issue(id).unwrap().unwrap();
+        assert_eq!(issue.title, "Renamed by remote");
+    }
+
+    #[test]
+    fn test_merge_keeps_newer_local_side_over_stale_remote_snapshot() {
+        let (remote, dir) = setup_test_db();
+        let id = remote.create_issue("Shared", None, "medium").unwrap();
+        let export_path = dir.path().join("export.json");
+        export::run_json(&remote, Some(export_path.to_str().unwrap())).unwrap();
+
+        // Sync once so both sides agree the row is the same issue (same id, same created_at).
+        let (local, _local_dir) = setup_test_db();
+        run(&local, &export_path, "merge").unwrap();
+
+        local.update_issue(id, Some("Renamed locally"), None, None, None).unwrap();
+        // Re-merge the stale remote snapshot: local's later edit must not be clobbered.
+        run(&local, &export_path, "merge").unwrap();
+
+        let issue = local.get_issue(id).unwrap().unwrap();
+        assert_eq!(issue.title, "Renamed locally");
+    }
+
+    #[test]
+    fn test_merge_remaps_colliding_ids_for_unrelated_issues() {
+        let (remote, dir) = setup_test_db();
+        remote.create_issue("Remote issue", None, "medium").unwrap();
+        let export_path = dir.path().join("export.json");
+        export::run_json(&remote, Some(export_path.to_str().unwrap())).unwrap();
+
+        let (local, _local_dir) = setup_test_db();
+        let local_id = local.create_issue("Unrelated local issue", None, "medium").unwrap();
+        run(&local, &export_path, "merge").unwrap();
+
+        let issues = local.list_issues(Some("all"), None, None).unwrap();
+        assert_eq!(issues.len(), 2);
+        let local_issue = local.get_issue(local_id).unwrap().unwrap();
+        assert_eq!(local_issue.title, "Unrelated local issue");
+        assert!(issues.iter().any(|i| i.title == "Remote issue" && i.id != local_id));
+    }
+
+    #[test]
+    fn test_merge_unions_comments_without_duplicating() {
+        let (remote, dir) = setup_test_db();
+        let id = remote.create_issue("Shared", None, "medium").unwrap();
+        remote.add_comment(id, "from remote").unwrap();
+        let export_path = dir.path().join("export.json");
+        export::run_json(&remote, Some(export_path.to_str().unwrap())).unwrap();
+
+        // Sync once so both sides agree the row is the same issue (same id, same created_at).
+        let (local, _local_dir) = setup_test_db();
+        run(&local, &export_path, "merge").unwrap();
+
+        local.add_comment(id, "from local").unwrap();
+        // Re-merging the same remote snapshot must not duplicate "from remote" nor drop the
+        // comment local added since the first sync.
+        run(&local, &export_path, "merge").unwrap();
+
+        let comments = local.get_comments(id).unwrap();
+        assert_eq!(comments.len(), 2);
+    }
+}
@@ -11,7 +11,7 @@ const PROMPT_GUARD_PY: &str = include_str!("../../../.claude/hooks/prompt-guard.
 const POST_EDIT_CHECK_PY: &str = include_str!("../../../.claude/hooks/post-edit-check.py");
 const SESSION_START_PY: &str = include_str!("../../../.claude/hooks/session-start.py");
 
-pub fn run(path: &Path) -> Result<()> {
+pub fn run(path: &Path, passphrase: Option<&str>) -> Result<()> {
     let chainlink_dir = path.join(".chainlink");
     let claude_dir = path.join(".claude");
     let hooks_dir = claude_dir.join("hooks");
@@ -31,8 +31,16 @@ pub fn run(path: &Path) -> Result<()> {
             .context("Failed to create .chainlink directory")?;
 
         let db_path = chainlink_dir.join("issues.db");
-        Database::open(&db_path)?;
-        println!("Created {}", chainlink_dir.display());
+        match passphrase {
+            Some(passphrase) => {
+                Database::open_encrypted(&db_path, passphrase)?;
+                println!("Created {} (encrypted)", chainlink_dir.display());
+            }
+            None => {
+                Database::open(&db_path)?;
+                println!("Created {}", chainlink_dir.display());
+            }
+        }
     }
 
     // Create .claude directory and hooks
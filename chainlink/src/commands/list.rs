@@ -7,8 +7,13 @@ pub fn run(
     status: Option<&str>,
     label: Option<&str>,
     priority: Option<&str>,
+    over_estimate: bool,
 ) -> Result<()> {
-    let issues = db.list_issues(status, label, priority)?;
+    let issues = if over_estimate {
+        db.list_over_estimate_issues()?
+    } else {
+        db.list_issues(status, label, priority)?
+    };
 
     if issues.is_empty() {
         println!("No issues found.");
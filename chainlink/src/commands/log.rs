@@ -0,0 +1,62 @@
+use anyhow::{bail, Result};
+
+use crate::commands::timer::parse_duration;
+use crate::db::Database;
+
+/// Logs time spent on an issue directly, for work that wasn't tracked with `start`/`stop`.
+pub fn run(db: &Database, id: i64, duration: &str) -> Result<()> {
+    let issue = match db.get_issue(id)? {
+        Some(i) => i,
+        None => bail!("Issue #{} not found", id),
+    };
+
+    let seconds = parse_duration(duration)?;
+    db.log_time(id, seconds)?;
+
+    let total = db.get_total_time(id)?;
+    println!("Logged {} on #{}: {}", duration, id, issue.title);
+    println!("Total time on this issue: {}h {}m", total / 3600, (total % 3600) / 60);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_log_adds_to_total_time() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Test", None, "medium").unwrap();
+
+        run(&db, id, "2h").unwrap();
+        run(&db, id, "30m").unwrap();
+
+        assert_eq!(db.get_total_time(id).unwrap(), 9000);
+    }
+
+    #[test]
+    fn test_log_nonexistent_issue() {
+        let (db, _dir) = setup_test_db();
+        let result = run(&db, 99999, "1h");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_log_invalid_duration() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Test", None, "medium").unwrap();
+
+        let result = run(&db, id, "nonsense");
+        assert!(result.is_err());
+    }
+}
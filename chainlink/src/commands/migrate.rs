@@ -0,0 +1,66 @@
+use anyhow::Result;
+
+use crate::db::Database;
+use crate::migrations;
+
+pub fn status(db: &Database) -> Result<()> {
+    let statuses = migrations::status(&db.conn()?)?;
+    let current = statuses.iter().filter(|s| s.applied).count();
+
+    println!("Schema version: {}/{}", current, statuses.len());
+    for s in &statuses {
+        let marker = if s.applied { "x" } else { " " };
+        println!("  [{}] V{}__{}", marker, s.version, s.name);
+    }
+
+    Ok(())
+}
+
+pub fn to_version(db: &Database, version: i32) -> Result<()> {
+    migrations::migrate_to(&db.conn()?, version)?;
+    println!("Migrated to schema version {}", version);
+    status(db)
+}
+
+pub fn run(db: &Database) -> Result<()> {
+    // Database::open() already applies every pending migration, so by the time this command
+    // runs there's nothing left to do beyond reporting the (now up to date) state.
+    status(db)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_status_all_applied_on_open() {
+        let (db, _dir) = setup_test_db();
+        let result = status(&db);
+        assert!(result.is_ok());
+
+        let statuses = migrations::status(&db.conn().unwrap()).unwrap();
+        assert!(statuses.iter().all(|s| s.applied));
+    }
+
+    #[test]
+    fn test_to_version_rolls_back_and_forward() {
+        let (db, _dir) = setup_test_db();
+
+        to_version(&db, 1).unwrap();
+        let statuses = migrations::status(&db.conn().unwrap()).unwrap();
+        assert!(statuses[0].applied);
+        assert!(!statuses[1].applied);
+
+        to_version(&db, migrations::MIGRATIONS.len() as i32).unwrap();
+        let statuses = migrations::status(&db.conn().unwrap()).unwrap();
+        assert!(statuses.iter().all(|s| s.applied));
+    }
+}
@@ -1,7 +1,18 @@
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
 
+use crate::commands::status;
 use crate::db::Database;
 
+/// All seven Keep a Changelog sections `determine_changelog_category` can produce, in the repo's
+/// conventional ordering. `notes` pre-creates empty headings for all of them (not just the four
+/// `status::UNRELEASED_BLOCK` bootstraps), so the milestone's own section is always the first
+/// match `append_to_changelog` finds for any category, regardless of which labels its issues have.
+const CHANGELOG_CATEGORIES: [&str; 7] =
+    ["Added", "Fixed", "Changed", "Breaking", "Deprecated", "Removed", "Security"];
+
 pub fn create(db: &Database, name: &str, description: Option<&str>) -> Result<()> {
     let id = db.create_milestone(name, description)?;
     println!("Created milestone #{}: {}", id, name);
@@ -127,6 +138,89 @@ pub fn delete(db: &Database, id: i64) -> Result<()> {
     Ok(())
 }
 
+/// Groups the milestone's closed issues under their Keep a Changelog sections (same mapping
+/// `status::close` uses for a single issue) and prints them as Markdown. With `write`, also
+/// drops the same grouped entries into `CHANGELOG.md` under a new `## [{milestone name}]`
+/// heading, so closing out a milestone can produce a ready-to-ship release section in one step.
+pub fn notes(db: &Database, id: i64, write: bool, chainlink_dir: &Path) -> Result<()> {
+    let milestone = match db.get_milestone(id)? {
+        Some(m) => m,
+        None => bail!("Milestone #{} not found", id),
+    };
+
+    let issues = db.get_milestone_issues(id)?;
+    let mut grouped: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for issue in issues.iter().filter(|i| i.status == "closed") {
+        let labels = db.get_labels(issue.id)?;
+        let category = status::determine_changelog_category(&labels);
+        grouped.entry(category).or_default().push(format!("- {} (#{})", issue.title, issue.id));
+    }
+
+    if grouped.is_empty() {
+        println!("No closed issues in milestone #{}; nothing to report.", id);
+        return Ok(());
+    }
+
+    for category in CHANGELOG_CATEGORIES {
+        if let Some(entries) = grouped.get(category) {
+            println!("### {}\n", category);
+            for entry in entries {
+                println!("{}", entry);
+            }
+            println!();
+        }
+    }
+
+    if write {
+        let project_root = chainlink_dir.parent().unwrap_or(chainlink_dir);
+        let changelog_path = project_root.join("CHANGELOG.md");
+
+        if !changelog_path.exists() {
+            status::create_changelog(&changelog_path)?;
+            println!("Created CHANGELOG.md");
+        }
+
+        insert_milestone_heading(&changelog_path, &milestone.name)?;
+        for category in CHANGELOG_CATEGORIES {
+            if let Some(entries) = grouped.get(category) {
+                let block: String = entries.iter().map(|e| format!("{}\n", e)).collect();
+                status::append_to_changelog(&changelog_path, category, &block)?;
+            }
+        }
+        println!("Added release notes for milestone #{} to CHANGELOG.md", id);
+    }
+
+    Ok(())
+}
+
+/// Inserts `## [{name}]` with all seven empty Keep a Changelog sections directly above the
+/// changelog's first existing `## ` heading (typically `## [Unreleased]`), so the milestone's
+/// headings are always the first occurrence `append_to_changelog` finds for each category.
+fn insert_milestone_heading(path: &Path, name: &str) -> Result<()> {
+    let content = fs::read_to_string(path).context("Failed to read CHANGELOG.md")?;
+    let mut heading_block = format!("## [{}]\n\n", name);
+    for category in CHANGELOG_CATEGORIES {
+        heading_block.push_str(&format!("### {}\n\n", category));
+    }
+
+    let mut result = String::new();
+    let mut inserted = false;
+    for line in content.lines() {
+        if !inserted && line.starts_with("## ") {
+            result.push_str(&heading_block);
+            inserted = true;
+        }
+        result.push_str(line);
+        result.push('\n');
+    }
+    if !inserted {
+        result.push_str(&heading_block);
+    }
+
+    fs::write(path, result).context("Failed to write CHANGELOG.md")?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
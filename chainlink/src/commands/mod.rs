@@ -0,0 +1,28 @@
+pub mod archive;
+pub mod backup;
+pub mod batch;
+pub mod create;
+pub mod delete;
+pub mod deps;
+pub mod doctor;
+pub mod epic;
+pub mod export;
+pub mod history;
+pub mod import;
+pub mod init;
+pub mod list;
+pub mod log;
+pub mod migrate;
+pub mod milestone;
+pub mod next;
+pub mod release;
+pub mod search;
+pub mod show;
+pub mod stats;
+pub mod status;
+pub mod tested;
+pub mod timer;
+pub mod timesheet;
+pub mod tree;
+pub mod update;
+pub mod watch;
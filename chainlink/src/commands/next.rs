@@ -26,16 +26,11 @@ fn calculate_progress(db: &Database, issue: &Issue) -> Result<Option<(i32, i32)>
     Ok(Some((closed, total)))
 }
 
-pub fn run(db: &Database) -> Result<()> {
+/// Scores and sorts every ready, non-subissue issue (highest priority, with a bonus for ones
+/// already partway done) for `next::run`'s recommendation. Shared with `serve`'s `/next`
+/// endpoint so both report the same ranking.
+pub(crate) fn score_ready_issues(db: &Database) -> Result<Vec<(Issue, i32, Option<(i32, i32)>)>> {
     let ready = db.list_ready_issues()?;
-
-    if ready.is_empty() {
-        println!("No issues ready to work on.");
-        println!("Use 'chainlink list' to see all issues or 'chainlink blocked' to see blocked issues.");
-        return Ok(());
-    }
-
-    // Score and sort issues
     let mut scored: Vec<(Issue, i32, Option<(i32, i32)>)> = Vec::new();
 
     for issue in ready {
@@ -59,17 +54,25 @@ pub fn run(db: &Database) -> Result<()> {
 
     // Sort by score descending
     scored.sort_by(|a, b| b.1.cmp(&a.1));
+    Ok(scored)
+}
+
+pub fn run(db: &Database) -> Result<()> {
+    let scored = score_ready_issues(db)?;
 
     if scored.is_empty() {
-        // All ready issues are subissues, show them instead
         let ready = db.list_ready_issues()?;
         if let Some(issue) = ready.first() {
+            // All ready issues are subissues, show them instead
             println!("Next: #{} [{}] {}", issue.id, issue.priority, issue.title);
             if let Some(parent_id) = issue.parent_id {
                 println!("       (subissue of #{})", parent_id);
             }
         } else {
             println!("No issues ready to work on.");
+            println!(
+                "Use 'chainlink list' to see all issues or 'chainlink blocked' to see blocked issues."
+            );
         }
         return Ok(());
     }
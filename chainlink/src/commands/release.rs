@@ -0,0 +1,289 @@
+//! Cuts a dated version from `CHANGELOG.md`'s `## [Unreleased]` section: renames the heading
+//! to `## [X.Y.Z] - YYYY-MM-DD`, inserts a fresh empty Unreleased block above it, and infers
+//! the semver bump from which sections the outgoing Unreleased block has entries in.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::commands::status;
+
+pub fn run(chainlink_dir: &Path, version_override: Option<&str>) -> Result<()> {
+    let project_root = chainlink_dir.parent().unwrap_or(chainlink_dir);
+    let changelog_path = project_root.join("CHANGELOG.md");
+
+    let content = fs::read_to_string(&changelog_path)
+        .with_context(|| format!("Failed to read {}", changelog_path.display()))?;
+    let lines: Vec<&str> = content.lines().collect();
+
+    let unreleased_idx = lines
+        .iter()
+        .position(|line| line.trim() == "## [Unreleased]")
+        .context("CHANGELOG.md has no ## [Unreleased] section to release")?;
+    let end_idx = lines[unreleased_idx + 1..]
+        .iter()
+        .position(|line| line.starts_with("## "))
+        .map(|offset| unreleased_idx + 1 + offset)
+        .unwrap_or(lines.len());
+    let body = &lines[unreleased_idx + 1..end_idx];
+
+    let bump = infer_bump(body);
+    let next_version = match version_override {
+        Some(v) => Version::parse(v)?,
+        None => {
+            let previous = find_latest_version(&lines).unwrap_or(Version::default());
+            previous.bump(bump)
+        }
+    };
+
+    let date = Utc::now().format("%Y-%m-%d");
+    let mut new_content = String::with_capacity(content.len() + 64);
+    for line in &lines[..unreleased_idx] {
+        new_content.push_str(line);
+        new_content.push('\n');
+    }
+    new_content.push_str(status::UNRELEASED_BLOCK);
+    new_content.push('\n');
+    new_content.push_str(&format!("## [{}] - {}\n", next_version, date));
+    for line in body {
+        new_content.push_str(line);
+        new_content.push('\n');
+    }
+    for line in &lines[end_idx..] {
+        new_content.push_str(line);
+        new_content.push('\n');
+    }
+
+    fs::write(&changelog_path, new_content)
+        .with_context(|| format!("Failed to write {}", changelog_path.display()))?;
+
+    println!("{}", next_version);
+    Ok(())
+}
+
+/// Major if the Unreleased block has entries in whichever section a `breaking`-labeled or
+/// `removed`-labeled issue would land in (`### Breaking`/`### Removed`, per
+/// `determine_changelog_category` — both represent a compatibility break); otherwise minor if
+/// it has `### Added` entries (a `feature`-labeled issue's section); otherwise patch. A plain
+/// closed issue with no recognized label lands in `### Changed` (the default category) and does
+/// not by itself force a major bump.
+fn infer_bump(body: &[&str]) -> Bump {
+    let major_sections = [
+        status::determine_changelog_category(&["breaking".to_string()]),
+        status::determine_changelog_category(&["removed".to_string()]),
+    ];
+    let minor_section = status::determine_changelog_category(&["feature".to_string()]);
+
+    if major_sections.iter().any(|category| section_has_entries(body, category)) {
+        Bump::Major
+    } else if section_has_entries(body, &minor_section) {
+        Bump::Minor
+    } else {
+        Bump::Patch
+    }
+}
+
+/// Whether `### {category}` has at least one non-blank line under it before the next `###`
+/// (or the end of the Unreleased block).
+fn section_has_entries(body: &[&str], category: &str) -> bool {
+    let heading = format!("### {}", category);
+    let mut in_section = false;
+    for line in body {
+        let trimmed = line.trim();
+        if trimmed == heading {
+            in_section = true;
+            continue;
+        }
+        if in_section {
+            if trimmed.starts_with("### ") || trimmed.starts_with("## ") {
+                break;
+            }
+            if !trimmed.is_empty() {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Finds the topmost `## [x.y.z]` heading in the changelog, skipping `## [Unreleased]` — the
+/// version `release` bumps from when `--version` isn't given.
+fn find_latest_version(lines: &[&str]) -> Option<Version> {
+    lines.iter().find_map(|line| {
+        let rest = line.trim().strip_prefix("## [")?;
+        let heading = &rest[..rest.find(']')?];
+        if heading.eq_ignore_ascii_case("unreleased") {
+            return None;
+        }
+        Version::parse(heading).ok()
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Bump {
+    Major,
+    Minor,
+    Patch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl Version {
+    fn parse(s: &str) -> Result<Version> {
+        let mut parts = s.trim().split('.');
+        let major = parts
+            .next()
+            .context("version is missing a major component")?
+            .parse()
+            .with_context(|| format!("invalid major version in '{}'", s))?;
+        let minor = parts
+            .next()
+            .context("version is missing a minor component")?
+            .parse()
+            .with_context(|| format!("invalid minor version in '{}'", s))?;
+        let patch = parts
+            .next()
+            .context("version is missing a patch component")?
+            .parse()
+            .with_context(|| format!("invalid patch version in '{}'", s))?;
+        anyhow::ensure!(parts.next().is_none(), "version '{}' has more than three components", s);
+        Ok(Version { major, minor, patch })
+    }
+
+    fn bump(self, bump: Bump) -> Version {
+        match bump {
+            Bump::Major => Version { major: self.major + 1, minor: 0, patch: 0 },
+            Bump::Minor => Version { major: self.major, minor: self.minor + 1, patch: 0 },
+            Bump::Patch => Version { major: self.major, minor: self.minor, patch: self.patch + 1 },
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    /// Mirrors how `find_chainlink_dir` lays things out: `CHANGELOG.md` lives next to the
+    /// `.chainlink` directory, not inside it, so `run` is given the `.chainlink` subdir while
+    /// the changelog is written into its parent.
+    fn setup(project_root: &Path, body: &str) -> std::path::PathBuf {
+        let chainlink_dir = project_root.join(".chainlink");
+        fs::create_dir_all(&chainlink_dir).unwrap();
+        fs::write(project_root.join("CHANGELOG.md"), body).unwrap();
+        chainlink_dir
+    }
+
+    #[test]
+    fn test_version_parse_roundtrip() {
+        let v = Version::parse("1.2.3").unwrap();
+        assert_eq!(v, Version { major: 1, minor: 2, patch: 3 });
+        assert_eq!(v.to_string(), "1.2.3");
+    }
+
+    #[test]
+    fn test_version_parse_rejects_malformed() {
+        assert!(Version::parse("1.2").is_err());
+        assert!(Version::parse("1.2.3.4").is_err());
+        assert!(Version::parse("a.b.c").is_err());
+    }
+
+    #[test]
+    fn test_bump_major_minor_patch() {
+        let v = Version { major: 1, minor: 2, patch: 3 };
+        assert_eq!(v.bump(Bump::Major), Version { major: 2, minor: 0, patch: 0 });
+        assert_eq!(v.bump(Bump::Minor), Version { major: 1, minor: 3, patch: 0 });
+        assert_eq!(v.bump(Bump::Patch), Version { major: 1, minor: 2, patch: 4 });
+    }
+
+    #[test]
+    fn test_infer_bump_patch_when_all_sections_empty() {
+        let body = ["", "### Added", "", "### Fixed", "", "### Changed", ""];
+        assert_eq!(infer_bump(&body), Bump::Patch);
+    }
+
+    #[test]
+    fn test_infer_bump_minor_when_added_has_entries() {
+        let body = ["", "### Added", "- New widget (#12)", "", "### Fixed", ""];
+        assert_eq!(infer_bump(&body), Bump::Minor);
+    }
+
+    #[test]
+    fn test_infer_bump_major_when_breaking_has_entries() {
+        let body = ["", "### Added", "", "### Breaking", "- Reworked API (#7)", ""];
+        assert_eq!(infer_bump(&body), Bump::Major);
+    }
+
+    #[test]
+    fn test_infer_bump_patch_when_only_changed_has_entries() {
+        // An unlabeled closed issue lands in the default `### Changed` section and must not,
+        // on its own, force a major bump — only `### Breaking`/`### Removed` do.
+        let body = ["", "### Added", "", "### Changed", "- Tweaked wording (#9)", ""];
+        assert_eq!(infer_bump(&body), Bump::Patch);
+    }
+
+    #[test]
+    fn test_find_latest_version_skips_unreleased() {
+        let content = "## [Unreleased]\n\n## [1.4.0] - 2026-01-01\n\n## [1.3.0] - 2025-01-01\n";
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(find_latest_version(&lines), Some(Version { major: 1, minor: 4, patch: 0 }));
+    }
+
+    #[test]
+    fn test_find_latest_version_none_for_first_release() {
+        let content = "## [Unreleased]\n\n### Added\n";
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(find_latest_version(&lines), None);
+    }
+
+    #[test]
+    fn test_run_cuts_first_release_as_minor() {
+        let dir = tempdir().unwrap();
+        let chainlink_dir = setup(
+            dir.path(),
+            "# Changelog\n\n## [Unreleased]\n\n### Added\n- Initial release (#1)\n\n### Fixed\n\n### Changed\n",
+        );
+
+        let result = run(&chainlink_dir, None);
+        assert!(result.is_ok());
+
+        let updated = fs::read_to_string(dir.path().join("CHANGELOG.md")).unwrap();
+        assert!(updated.contains("## [Unreleased]"));
+        assert!(updated.contains("## [0.1.0] -"));
+        assert!(updated.contains("Initial release (#1)"));
+    }
+
+    #[test]
+    fn test_run_respects_version_override() {
+        let dir = tempdir().unwrap();
+        let chainlink_dir = setup(
+            dir.path(),
+            "# Changelog\n\n## [Unreleased]\n\n### Added\n\n## [1.0.0] - 2025-01-01\n",
+        );
+
+        assert!(run(&chainlink_dir, Some("9.9.9")).is_ok());
+        let updated = fs::read_to_string(dir.path().join("CHANGELOG.md")).unwrap();
+        assert!(updated.contains("## [9.9.9] -"));
+    }
+
+    #[test]
+    fn test_run_fails_without_unreleased_section() {
+        let dir = tempdir().unwrap();
+        let chainlink_dir = setup(dir.path(), "# Changelog\n\n## [1.0.0] - 2025-01-01\n");
+
+        assert!(run(&chainlink_dir, None).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use anyhow::{bail, Result};
+
+use crate::db::Database;
+
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    db: &Database,
+    query: &str,
+    status: Option<&str>,
+    priority: Option<&str>,
+    snippet: bool,
+    limit: i64,
+    offset: i64,
+) -> Result<()> {
+    if query.trim().is_empty() {
+        bail!("Search query cannot be empty");
+    }
+
+    let hits = db.search_issues(query, status, priority, limit, offset)?;
+
+    if hits.is_empty() {
+        println!("No issues matched '{}'.", query);
+        return Ok(());
+    }
+
+    for hit in hits {
+        let issue = &hit.issue;
+        println!(
+            "#{:<4} [{}] {:8} {}",
+            issue.id, issue.status, issue.priority, issue.title
+        );
+        if snippet && !hit.snippet.is_empty() {
+            println!("      {}", hit.snippet.replace('\n', " "));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_search_matches_title() {
+        let (db, _dir) = setup_test_db();
+        db.create_issue("Fix login bug", None, "high").unwrap();
+        db.create_issue("Add dashboard widget", None, "low")
+            .unwrap();
+
+        let result = run(&db, "login", None, None, false, 20, 0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_search_empty_query_fails() {
+        let (db, _dir) = setup_test_db();
+        let result = run(&db, "   ", None, None, false, 20, 0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_search_no_matches() {
+        let (db, _dir) = setup_test_db();
+        db.create_issue("Unrelated issue", None, "medium").unwrap();
+
+        let result = run(&db, "nonexistent", None, None, false, 20, 0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_search_with_snippet() {
+        let (db, _dir) = setup_test_db();
+        db.create_issue("Fix login bug", Some("Users can't log in"), "high")
+            .unwrap();
+
+        let result = run(&db, "login", None, None, true, 20, 0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_search_filtered_by_status() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Fix login bug", None, "high").unwrap();
+        db.close_issue(id).unwrap();
+
+        let result = run(&db, "login", Some("closed"), None, false, 20, 0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_search_matches_comment_body() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Dashboard widget", None, "medium").unwrap();
+        db.add_comment(id, "Turns out this breaks on Safari").unwrap();
+
+        let hits = db.search_issues("safari", None, None, 20, 0).unwrap();
+        assert!(hits.iter().any(|h| h.issue.id == id));
+    }
+
+    #[test]
+    fn test_search_issue_and_comment_match_not_duplicated() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("Safari rendering bug", None, "medium").unwrap();
+        db.add_comment(id, "Also reproduces on Safari mobile").unwrap();
+
+        let hits = db.search_issues("safari", None, None, 20, 0).unwrap();
+        assert_eq!(hits.iter().filter(|h| h.issue.id == id).count(), 1);
+    }
+
+    #[test]
+    fn test_search_special_characters_do_not_error() {
+        let (db, _dir) = setup_test_db();
+        db.create_issue("Handle edge-case: null/undefined", None, "medium")
+            .unwrap();
+
+        // Characters like '-', ':', '"' are FTS5 operators/quote delimiters when unescaped.
+        let result = run(&db, "edge-case: \"null\"", None, None, false, 20, 0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_search_prefix_match() {
+        let (db, _dir) = setup_test_db();
+        db.create_issue("Authentication refactor", None, "medium")
+            .unwrap();
+
+        let hits = db.search_issues("auth*", None, None, 20, 0).unwrap();
+        assert!(!hits.is_empty());
+    }
+
+    #[test]
+    fn test_search_tolerates_two_char_typo() {
+        let (db, _dir) = setup_test_db();
+        db.create_issue("Authentication refactor", None, "medium")
+            .unwrap();
+
+        // "authentification" is within edit distance 2 of "authentication" (len >= 8).
+        let hits = db
+            .search_issues("authentification", None, None, 20, 0)
+            .unwrap();
+        assert!(!hits.is_empty());
+    }
+
+    #[test]
+    fn test_search_respects_limit_and_offset() {
+        let (db, _dir) = setup_test_db();
+        for i in 0..5 {
+            db.create_issue(&format!("Login bug #{}", i), None, "medium")
+                .unwrap();
+        }
+
+        let page1 = db.search_issues("login", None, None, 2, 0).unwrap();
+        let page2 = db.search_issues("login", None, None, 2, 2).unwrap();
+        assert_eq!(page1.len(), 2);
+        assert_eq!(page2.len(), 2);
+        assert_ne!(page1[0].issue.id, page2[0].issue.id);
+    }
+}
@@ -14,6 +14,16 @@ pub fn run(db: &Database, id: i64) -> Result<()> {
     if let Some(parent_id) = issue.parent_id {
         println!("Parent: #{}", parent_id);
     }
+    let time_spent = db.get_total_time(id)?;
+    if issue.estimate_seconds.is_some() || time_spent > 0 {
+        println!("Estimate: {}", format_duration_opt(issue.estimate_seconds));
+        println!("Spent: {}", format_duration(time_spent));
+        if let Some(estimate) = issue.estimate_seconds {
+            let remaining = estimate - time_spent;
+            println!("Remaining: {}", format_duration(remaining));
+        }
+    }
+
     println!("Created: {}", issue.created_at.format("%Y-%m-%d %H:%M:%S"));
     println!("Updated: {}", issue.updated_at.format("%Y-%m-%d %H:%M:%S"));
 
@@ -80,3 +90,17 @@ pub fn run(db: &Database, id: i64) -> Result<()> {
 
     Ok(())
 }
+
+fn format_duration(seconds: i64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    match (hours, minutes) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h{}m", h, m),
+    }
+}
+
+fn format_duration_opt(seconds: Option<i64>) -> String {
+    seconds.map(format_duration).unwrap_or_else(|| "none".to_string())
+}
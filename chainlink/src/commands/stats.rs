@@ -0,0 +1,251 @@
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use serde::Serialize;
+
+use crate::analytics::{self, BacklogCount, BurndownPoint, Granularity};
+use crate::commands::timer::format_duration;
+use crate::db::Database;
+
+#[derive(Debug, Serialize)]
+struct CycleTime {
+    sample_size: usize,
+    median_seconds: Option<f64>,
+    p90_seconds: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+struct BlockedVsReady {
+    blocked: i64,
+    ready: i64,
+    ratio: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+struct StatsReport {
+    throughput: Vec<BurndownPoint>,
+    cycle_time: CycleTime,
+    open_backlog: Vec<BacklogCount>,
+    blocked_vs_ready: BlockedVsReady,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    db: &Database,
+    since: Option<&str>,
+    until: Option<&str>,
+    group_by: &str,
+    label: Option<&str>,
+    priority: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let granularity = match group_by {
+        "day" => Granularity::Day,
+        "week" => Granularity::Week,
+        other => bail!("Invalid --group-by '{}'. Must be one of: day, week", other),
+    };
+    let from = since.map(parse_date).transpose()?;
+    let to = until.map(parse_date).transpose()?;
+
+    let conn = db.conn()?;
+    let throughput = analytics::burndown(&conn, granularity, from, to, priority, label)?;
+    let cycle_time = cycle_time_summary(&conn, from, to, priority, label)?;
+    let open_backlog = analytics::open_backlog_by_priority(&conn, priority, label)?;
+    let blocked_vs_ready = blocked_vs_ready(db, label, priority)?;
+
+    let report = StatsReport {
+        throughput,
+        cycle_time,
+        open_backlog,
+        blocked_vs_ready,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_table(&report, granularity);
+    }
+
+    Ok(())
+}
+
+fn cycle_time_summary(
+    conn: &rusqlite::Connection,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    priority: Option<&str>,
+    label: Option<&str>,
+) -> Result<CycleTime> {
+    let mut samples = analytics::cycle_times(conn, from, to, priority, label)?;
+    samples.sort_unstable();
+
+    Ok(CycleTime {
+        sample_size: samples.len(),
+        median_seconds: percentile(&samples, 50.0),
+        p90_seconds: percentile(&samples, 90.0),
+    })
+}
+
+/// Nearest-rank percentile over an already-sorted slice.
+fn percentile(sorted: &[i64], pct: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((pct / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+    Some(sorted[rank] as f64)
+}
+
+/// Counts of currently blocked/ready issues, restricted to `label`/`priority` the same way
+/// `analytics::open_backlog_by_priority` is (blocked/ready are graph properties, not something
+/// `analytics`'s date-range queries model, so this filters `db`'s own lists directly).
+fn blocked_vs_ready(db: &Database, label: Option<&str>, priority: Option<&str>) -> Result<BlockedVsReady> {
+    let matches = |issue: &crate::models::Issue| -> Result<bool> {
+        if let Some(priority) = priority {
+            if issue.priority != priority {
+                return Ok(false);
+            }
+        }
+        if let Some(label) = label {
+            if !db.get_labels(issue.id)?.iter().any(|l| l == label) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    };
+
+    let blocked = db
+        .list_blocked_issues()?
+        .iter()
+        .map(matches)
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|m| *m)
+        .count() as i64;
+    let ready = db
+        .list_ready_issues()?
+        .iter()
+        .map(matches)
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|m| *m)
+        .count() as i64;
+
+    let ratio = if ready > 0 {
+        Some(blocked as f64 / ready as f64)
+    } else {
+        None
+    };
+
+    Ok(BlockedVsReady { blocked, ready, ratio })
+}
+
+fn parse_date(s: &str) -> Result<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .with_context(|| format!("Invalid date '{}'. Expected YYYY-MM-DD", s))?;
+    Ok(Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap()))
+}
+
+fn print_table(report: &StatsReport, granularity: Granularity) {
+    let bucket_label = match granularity {
+        Granularity::Day => "Day",
+        Granularity::Week => "Week",
+    };
+
+    println!("Throughput ({}):", bucket_label);
+    if report.throughput.is_empty() {
+        println!("  No issues in range.");
+    } else {
+        for point in &report.throughput {
+            println!(
+                "  {:<10} opened {:<4} closed {:<4}",
+                point.bucket, point.opened, point.closed
+            );
+        }
+    }
+
+    println!("\nCycle time ({} closed issues):", report.cycle_time.sample_size);
+    match (report.cycle_time.median_seconds, report.cycle_time.p90_seconds) {
+        (Some(median), Some(p90)) => {
+            println!("  Median: {}", format_duration(median as i64));
+            println!("  p90:    {}", format_duration(p90 as i64));
+        }
+        _ => println!("  No closed issues in range."),
+    }
+
+    println!("\nOpen backlog by priority:");
+    if report.open_backlog.is_empty() {
+        println!("  No open issues.");
+    } else {
+        for bucket in &report.open_backlog {
+            println!("  {:<10} {}", bucket.priority, bucket.count);
+        }
+    }
+
+    println!("\nBlocked vs. ready:");
+    println!("  Blocked: {}", report.blocked_vs_ready.blocked);
+    println!("  Ready:   {}", report.blocked_vs_ready.ready);
+    match report.blocked_vs_ready.ratio {
+        Some(ratio) => println!("  Ratio:   {:.2}", ratio),
+        None => println!("  Ratio:   n/a (no ready issues)"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn setup_test_db() -> (Database, tempfile::TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let db = Database::open(&db_path).unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_run_rejects_invalid_group_by() {
+        let (db, _dir) = setup_test_db();
+        let result = run(&db, None, None, "fortnight", None, None, false);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Invalid --group-by"));
+    }
+
+    #[test]
+    fn test_run_rejects_invalid_date() {
+        let (db, _dir) = setup_test_db();
+        let result = run(&db, Some("not-a-date"), None, "day", None, None, false);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_run_succeeds_on_empty_database() {
+        let (db, _dir) = setup_test_db();
+        assert!(run(&db, None, None, "day", None, None, true).is_ok());
+    }
+
+    #[test]
+    fn test_percentile_empty_returns_none() {
+        assert_eq!(percentile(&[], 50.0), None);
+    }
+
+    #[test]
+    fn test_percentile_median_and_p90() {
+        let samples = vec![10, 20, 30, 40, 50];
+        assert_eq!(percentile(&samples, 50.0), Some(30.0));
+        assert_eq!(percentile(&samples, 90.0), Some(50.0));
+    }
+
+    #[test]
+    fn test_blocked_vs_ready_ratio() {
+        let (db, _dir) = setup_test_db();
+        let a = db.create_issue("A", None, "medium").unwrap();
+        let b = db.create_issue("B", None, "medium").unwrap();
+        let c = db.create_issue("C", None, "medium").unwrap();
+        db.add_dependency(a, b).unwrap();
+
+        let result = blocked_vs_ready(&db, None, None).unwrap();
+        assert_eq!(result.blocked, 1);
+        assert_eq!(result.ready, 2);
+        assert!((result.ratio.unwrap() - 0.5).abs() < f64::EPSILON);
+        let _ = c;
+    }
+}
@@ -48,31 +48,35 @@ pub fn close(db: &Database, id: i64, update_changelog: bool, chainlink_dir: &Pat
     Ok(())
 }
 
-fn create_changelog(path: &Path) -> Result<()> {
-    let template = r#"# Changelog
-
-All notable changes to this project will be documented in this file.
-
-The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.0.0/).
-
-## [Unreleased]
-
-### Added
-
-### Fixed
-
-### Changed
-"#;
+/// The `## [Unreleased]` heading plus its four empty Keep a Changelog sections. Shared by
+/// `create_changelog` (the initial file) and `commands::release` (the fresh block `release`
+/// inserts above each version it cuts), so both always start from the same empty shape.
+pub(crate) const UNRELEASED_BLOCK: &str =
+    "## [Unreleased]\n\n### Added\n\n### Fixed\n\n### Changed\n\n### Breaking\n";
+
+pub(crate) fn create_changelog(path: &Path) -> Result<()> {
+    let template = format!(
+        "# Changelog\n\n\
+         All notable changes to this project will be documented in this file.\n\n\
+         The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.0.0/).\n\n\
+         {}",
+        UNRELEASED_BLOCK
+    );
     fs::write(path, template).context("Failed to create CHANGELOG.md")?;
     Ok(())
 }
 
-fn determine_changelog_category(labels: &[String]) -> String {
+/// Maps an issue's labels onto the Keep a Changelog section `close` appends its entry under.
+/// `commands::release` reuses this (rather than hardcoding section names) to decide which
+/// sections force a major/minor version bump, so the two stay in lockstep if this mapping ever
+/// changes. `breaking` gets its own `### Breaking` section, kept separate from the `### Changed`
+/// default, so an unlabeled closed issue can never be mistaken for a breaking change.
+pub(crate) fn determine_changelog_category(labels: &[String]) -> String {
     for label in labels {
         match label.to_lowercase().as_str() {
             "bug" | "fix" | "bugfix" => return "Fixed".to_string(),
             "feature" | "enhancement" => return "Added".to_string(),
-            "breaking" | "breaking-change" => return "Changed".to_string(),
+            "breaking" | "breaking-change" => return "Breaking".to_string(),
             "deprecated" => return "Deprecated".to_string(),
             "removed" => return "Removed".to_string(),
             "security" => return "Security".to_string(),
@@ -82,7 +86,11 @@ fn determine_changelog_category(labels: &[String]) -> String {
     "Changed".to_string() // Default category
 }
 
-fn append_to_changelog(path: &Path, category: &str, entry: &str) -> Result<()> {
+/// Inserts `entry` under `### {category}` — the first such heading the whole file, adding a new
+/// `### {category}` section right after the first `## ` heading if none exists yet.
+/// `commands::milestone::notes` reuses this to drop each of its grouped entries into the version
+/// section it creates, same as `close` uses it for the Unreleased section.
+pub(crate) fn append_to_changelog(path: &Path, category: &str, entry: &str) -> Result<()> {
     let content = fs::read_to_string(path).context("Failed to read CHANGELOG.md")?;
     let heading = format!("### {}", category);
 
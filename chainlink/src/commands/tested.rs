@@ -1,3 +1,7 @@
+//! Marks the current tree as tested. `commands::watch` is what actually makes "resets on next
+//! code change" true: it polls tracked source files and deletes this marker the moment one is
+//! newer than it.
+
 use anyhow::{Context, Result};
 use std::fs;
 use std::path::Path;
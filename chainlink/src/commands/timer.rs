@@ -1,9 +1,94 @@
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use chrono::Utc;
 
 use crate::db::Database;
 
-pub fn start(db: &Database, issue_id: i64) -> Result<()> {
+/// Parses a human duration like `2h30m`, `1d`, or `90s` into seconds. Accepts any combination
+/// of `d`/`h`/`m`/`s` components (each an integer followed by its unit, in any order), so
+/// `30m2h` parses the same as `2h30m`.
+pub fn parse_duration(input: &str) -> Result<i64> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        bail!("Duration cannot be empty");
+    }
+
+    let mut seconds: i64 = 0;
+    let mut number = String::new();
+    let mut saw_unit = false;
+
+    for ch in trimmed.chars() {
+        if ch.is_ascii_digit() {
+            number.push(ch);
+            continue;
+        }
+
+        if number.is_empty() {
+            bail!("Invalid duration '{}': expected a number before '{}'", trimmed, ch);
+        }
+        let value: i64 = number.parse().context("Invalid duration number")?;
+        number.clear();
+
+        seconds += match ch {
+            'd' | 'D' => value * 86400,
+            'h' | 'H' => value * 3600,
+            'm' | 'M' => value * 60,
+            's' | 'S' => value,
+            _ => bail!("Invalid duration unit '{}' in '{}'. Use d, h, m, or s", ch, trimmed),
+        };
+        saw_unit = true;
+    }
+
+    if !number.is_empty() {
+        bail!("Invalid duration '{}': trailing number with no unit", trimmed);
+    }
+    if !saw_unit {
+        bail!("Invalid duration '{}': no unit found", trimmed);
+    }
+
+    Ok(seconds)
+}
+
+/// Parses a signed offset like `90m`, `1h30m`, `2h`, or `-45s` for `track`: an optional leading
+/// `-` to subtract, then the same unit grammar as `parse_duration`.
+pub fn parse_offset(input: &str) -> Result<i64> {
+    let trimmed = input.trim();
+    match trimmed.strip_prefix('-') {
+        Some(rest) => Ok(-parse_duration(rest)?),
+        None => parse_duration(trimmed),
+    }
+}
+
+/// Renders a duration as its largest two non-zero units, e.g. `1h`, `1h2m`, `2m5s`, `42s` — never
+/// the noisy `0h 0m 42s` that printing all three fields unconditionally produces. This crate only
+/// tracks whole seconds, so the sub-second `.NNN` fraction the `duration_as_human_string`
+/// technique allows for never applies here; the smallest unit shown is always a whole second.
+pub fn format_duration(total_seconds: i64) -> String {
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+
+    if hours > 0 {
+        if minutes > 0 {
+            format!("{}h{}m", hours, minutes)
+        } else {
+            format!("{}h", hours)
+        }
+    } else if minutes > 0 {
+        if seconds > 0 {
+            format!("{}m{}s", minutes, seconds)
+        } else {
+            format!("{}m", minutes)
+        }
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+/// Starts a timer on `issue_id`. If another issue already has an active timer, this normally
+/// bails so an abandoned timer doesn't silently lose time; pass `resume` to instead close that
+/// stale session via `Database::close_stale_timer` (recorded up to its last heartbeat) and
+/// proceed, for when the prior `start` was orphaned by a crash or reboot rather than forgotten.
+pub fn start(db: &Database, issue_id: i64, resume: bool) -> Result<()> {
     // Verify issue exists
     let issue = db.get_issue(issue_id)?;
     if issue.is_none() {
@@ -15,10 +100,15 @@ pub fn start(db: &Database, issue_id: i64) -> Result<()> {
     if let Some((active_id, _)) = db.get_active_timer()? {
         if active_id == issue_id {
             bail!("Timer already running for issue #{}", issue_id);
+        } else if resume {
+            if let Some(duration) = db.close_stale_timer(active_id)? {
+                println!("Closed stale timer for #{}: recorded {}.", active_id, format_duration(duration));
+            }
         } else {
             bail!(
-                "Timer already running for issue #{}. Stop it first with 'chainlink stop'.",
-                active_id
+                "Timer already running for issue #{}. Stop it first with 'chainlink stop', or pass \
+                 --resume to close it out and start #{} instead.",
+                active_id, issue_id
             );
         }
     }
@@ -44,37 +134,119 @@ pub fn stop(db: &Database) -> Result<()> {
     let issue = db.get_issue(issue_id)?;
     let title = issue.map(|i| i.title).unwrap_or_else(|| "(deleted)".to_string());
 
-    let hours = duration.num_hours();
-    let minutes = duration.num_minutes() % 60;
-    let seconds = duration.num_seconds() % 60;
-
     println!("Stopped timer for #{}: {}", issue_id, title);
-    println!("Time spent: {}h {}m {}s", hours, minutes, seconds);
+    println!("Time spent: {}", format_duration(duration.num_seconds()));
 
     // Show total time for this issue
     let total = db.get_total_time(issue_id)?;
-    let total_hours = total / 3600;
-    let total_minutes = (total % 3600) / 60;
-    println!("Total time on this issue: {}h {}m", total_hours, total_minutes);
+    println!("Total time on this issue: {}", format_duration(total));
+
+    Ok(())
+}
+
+/// Lists individual tracked-time sessions: every one for `issue_id` if given, ending with the
+/// grand total, or the most recent sessions across all issues otherwise.
+pub fn log(db: &Database, issue_id: Option<i64>) -> Result<()> {
+    if let Some(id) = issue_id {
+        if db.get_issue(id)?.is_none() {
+            bail!("Issue #{} not found", id);
+        }
+
+        let entries = db.list_time_entries(Some(id), 0)?;
+        if entries.is_empty() {
+            println!("No tracked sessions for #{}.", id);
+            return Ok(());
+        }
+
+        for entry in &entries {
+            println!(
+                "{}  ->  {}  {}",
+                entry.started_at.format("%Y-%m-%d %H:%M"),
+                entry.ended_at.map(|e| e.format("%Y-%m-%d %H:%M").to_string()).unwrap_or_else(|| "(running)".to_string()),
+                format_session_duration(entry.duration_seconds),
+            );
+        }
+
+        let total = db.get_total_time(id)?;
+        println!();
+        println!("Total time on #{}: {}", id, format_duration(total));
+    } else {
+        let entries = db.list_time_entries(None, 20)?;
+        if entries.is_empty() {
+            println!("No tracked sessions.");
+            return Ok(());
+        }
+
+        for entry in &entries {
+            println!(
+                "#{:<4} {}  ->  {}  {}",
+                entry.issue_id,
+                entry.started_at.format("%Y-%m-%d %H:%M"),
+                entry.ended_at.map(|e| e.format("%Y-%m-%d %H:%M").to_string()).unwrap_or_else(|| "(running)".to_string()),
+                format_session_duration(entry.duration_seconds),
+            );
+        }
+    }
 
     Ok(())
 }
 
-pub fn status(db: &Database) -> Result<()> {
+fn format_session_duration(seconds: Option<i64>) -> String {
+    match seconds {
+        Some(seconds) => format_duration(seconds),
+        None => "-".to_string(),
+    }
+}
+
+/// Records a completed time entry for work done without a running timer. A plain offset (e.g.
+/// `1h30m`) adds to the issue's total the same as `stop` would; a `-`-prefixed offset (e.g.
+/// `-30m`) subtracts from it instead, for correcting an over-counted session. Either way it's
+/// stored as an ordinary `time_entries` row, so `sessions`/`get_total_time` see it exactly like
+/// a live session.
+pub fn track(db: &Database, issue_id: i64, offset: &str) -> Result<()> {
+    let issue = match db.get_issue(issue_id)? {
+        Some(i) => i,
+        None => bail!("Issue #{} not found", issue_id),
+    };
+
+    let seconds = parse_offset(offset)?;
+    db.log_time(issue_id, seconds)?;
+
+    let total = db.get_total_time(issue_id)?;
+    if seconds < 0 {
+        println!("Subtracted {} from #{}: {}", &offset.trim_start_matches('-'), issue_id, issue.title);
+    } else {
+        println!("Tracked {} on #{}: {}", offset, issue_id, issue.title);
+    }
+    println!("Total time on this issue: {}", format_duration(total));
+
+    Ok(())
+}
+
+/// How long an active timer can go without a heartbeat before `status --recover` treats it as
+/// orphaned (process killed or machine rebooted) rather than merely long-running.
+const STALE_TIMER_THRESHOLD_SECS: i64 = 15 * 60;
+
+pub fn status(db: &Database, recover: bool) -> Result<()> {
+    if recover {
+        return recover_stale_timer(db);
+    }
+
     let active = db.get_active_timer()?;
 
     match active {
         Some((issue_id, started_at)) => {
+            // A plain `status` call doubles as a liveness ping, so a later `--recover` can tell
+            // an orphaned timer from one that's simply been running a long time.
+            db.record_heartbeat(issue_id)?;
+
             let duration = Utc::now().signed_duration_since(started_at);
-            let hours = duration.num_hours();
-            let minutes = duration.num_minutes() % 60;
-            let seconds = duration.num_seconds() % 60;
 
             let issue = db.get_issue(issue_id)?;
             let title = issue.map(|i| i.title).unwrap_or_else(|| "(deleted)".to_string());
 
             println!("Timer running: #{} {}", issue_id, title);
-            println!("Elapsed: {}h {}m {}s", hours, minutes, seconds);
+            println!("Elapsed: {}", format_duration(duration.num_seconds()));
         }
         None => {
             println!("No timer running.");
@@ -83,3 +255,100 @@ pub fn status(db: &Database) -> Result<()> {
 
     Ok(())
 }
+
+/// Detects an active timer that's gone silent for longer than `STALE_TIMER_THRESHOLD_SECS` (no
+/// `status` heartbeat in that window) and closes it into history via
+/// `Database::close_stale_timer`, for recovering from a crash or reboot that left a timer open
+/// with no process left to `stop` it.
+fn recover_stale_timer(db: &Database) -> Result<()> {
+    let Some((issue_id, last_seen)) = db.get_active_timer_last_seen()? else {
+        println!("No timer running.");
+        return Ok(());
+    };
+
+    let age = Utc::now().signed_duration_since(last_seen).num_seconds();
+    if age < STALE_TIMER_THRESHOLD_SECS {
+        println!(
+            "Timer for #{} was last seen {} ago; not stale enough to recover.",
+            issue_id,
+            format_duration(age)
+        );
+        return Ok(());
+    }
+
+    if let Some(duration) = db.close_stale_timer(issue_id)? {
+        println!("Recovered orphaned timer for #{}: recorded {}.", issue_id, format_duration(duration));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration_single_unit() {
+        assert_eq!(parse_duration("90s").unwrap(), 90);
+        assert_eq!(parse_duration("5m").unwrap(), 300);
+        assert_eq!(parse_duration("2h").unwrap(), 7200);
+        assert_eq!(parse_duration("1d").unwrap(), 86400);
+    }
+
+    #[test]
+    fn test_parse_duration_combined_units() {
+        assert_eq!(parse_duration("2h30m").unwrap(), 9000);
+        assert_eq!(parse_duration("1d2h").unwrap(), 93600);
+    }
+
+    #[test]
+    fn test_parse_duration_is_case_insensitive_and_order_independent() {
+        assert_eq!(parse_duration("2H30M").unwrap(), 9000);
+        assert_eq!(parse_duration("30m2h").unwrap(), 9000);
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_malformed_input() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("abc").is_err());
+        assert!(parse_duration("5").is_err());
+        assert!(parse_duration("h5").is_err());
+    }
+
+    #[test]
+    fn test_parse_offset_positive_matches_parse_duration() {
+        assert_eq!(parse_offset("90m").unwrap(), 5400);
+        assert_eq!(parse_offset("1h30m").unwrap(), 5400);
+        assert_eq!(parse_offset("45s").unwrap(), 45);
+    }
+
+    #[test]
+    fn test_parse_offset_negative_subtracts() {
+        assert_eq!(parse_offset("-30m").unwrap(), -1800);
+        assert_eq!(parse_offset("-1h").unwrap(), -3600);
+    }
+
+    #[test]
+    fn test_parse_offset_rejects_malformed_input() {
+        assert!(parse_offset("-").is_err());
+        assert!(parse_offset("-abc").is_err());
+    }
+
+    #[test]
+    fn test_format_duration_hours() {
+        assert_eq!(format_duration(3600), "1h");
+        assert_eq!(format_duration(3720), "1h2m");
+    }
+
+    #[test]
+    fn test_format_duration_minutes() {
+        assert_eq!(format_duration(120), "2m");
+        assert_eq!(format_duration(125), "2m5s");
+    }
+
+    #[test]
+    fn test_format_duration_seconds_only() {
+        assert_eq!(format_duration(42), "42s");
+        assert_eq!(format_duration(0), "0s");
+    }
+}
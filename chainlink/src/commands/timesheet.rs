@@ -0,0 +1,90 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+use crate::analytics::{self, Granularity};
+use crate::commands::timer::format_duration;
+use crate::db::Database;
+
+/// Aggregates `time_entries` within `[since, until]`, grouped by issue (with a grand total) and
+/// by day or week depending on how wide the range is. `since`/`until` each accept `today`,
+/// `week`, `month` as a shorthand for "N days back from now", or an explicit `YYYY-MM-DD` date.
+pub fn report(db: &Database, since: Option<&str>, until: Option<&str>) -> Result<()> {
+    let from = since.map(parse_range_bound).transpose()?;
+    let to = until.map(parse_range_bound).transpose()?;
+
+    let conn = db.conn()?;
+    let by_issue = analytics::tracked_time_by_issue(&conn, from, to, None)?;
+    if by_issue.is_empty() {
+        println!("No tracked time in range.");
+        return Ok(());
+    }
+
+    let granularity = match (from, to) {
+        (Some(from), Some(to)) if (to - from).num_days() > 14 => Granularity::Week,
+        _ => Granularity::Day,
+    };
+    let by_bucket = analytics::tracked_time_by_bucket(&conn, granularity, from, to)?;
+
+    println!("By issue:");
+    let mut grand_total = 0i64;
+    for entry in &by_issue {
+        let title = db
+            .get_issue(entry.issue_id)?
+            .map(|i| i.title)
+            .unwrap_or_else(|| "(deleted)".to_string());
+        println!("  #{:<4} {:<8} {}", entry.issue_id, format_duration(entry.total_seconds), title);
+        grand_total += entry.total_seconds;
+    }
+
+    let bucket_label = match granularity {
+        Granularity::Day => "day",
+        Granularity::Week => "week",
+    };
+    println!("\nBy {}:", bucket_label);
+    for bucket in &by_bucket {
+        println!("  {:<10} {}", bucket.bucket, format_duration(bucket.total_seconds));
+    }
+
+    println!("\nTotal: {}", format_duration(grand_total));
+
+    Ok(())
+}
+
+/// Parses one end of a timesheet range: `today`/`week`/`month` as a number of days back from
+/// midnight today, or an explicit `YYYY-MM-DD` date.
+fn parse_range_bound(s: &str) -> Result<DateTime<Utc>> {
+    let today = Utc::now().date_naive();
+    let date = match s {
+        "today" => today,
+        "week" => today - chrono::Duration::days(7),
+        "month" => today - chrono::Duration::days(30),
+        other => NaiveDate::parse_from_str(other, "%Y-%m-%d")
+            .with_context(|| format!("Invalid date '{}'. Expected YYYY-MM-DD, or today/week/month", other))?,
+    };
+    Ok(Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_range_bound_relative_keywords() {
+        let today = parse_range_bound("today").unwrap();
+        let week = parse_range_bound("week").unwrap();
+        let month = parse_range_bound("month").unwrap();
+        assert!(week < today);
+        assert!(month < week);
+    }
+
+    #[test]
+    fn test_parse_range_bound_explicit_date() {
+        let parsed = parse_range_bound("2024-01-15").unwrap();
+        assert_eq!(parsed.format("%Y-%m-%d").to_string(), "2024-01-15");
+    }
+
+    #[test]
+    fn test_parse_range_bound_rejects_malformed_input() {
+        assert!(parse_range_bound("not-a-date").is_err());
+    }
+}
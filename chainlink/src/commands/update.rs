@@ -1,17 +1,27 @@
 use anyhow::{bail, Result};
 
 use crate::commands::create::validate_priority;
+use crate::commands::timer::parse_duration;
 use crate::db::Database;
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     db: &Database,
-    id: i64,
+    ids: &[i64],
     title: Option<&str>,
     description: Option<&str>,
     priority: Option<&str>,
+    estimate: Option<&str>,
+    epic: Option<i64>,
+    keep_history: Option<i64>,
 ) -> Result<()> {
-    if title.is_none() && description.is_none() && priority.is_none() {
-        bail!("Nothing to update. Use --title, --description, or --priority");
+    if title.is_none()
+        && description.is_none()
+        && priority.is_none()
+        && estimate.is_none()
+        && epic.is_none()
+    {
+        bail!("Nothing to update. Use --title, --description, --priority, --estimate, or --epic");
     }
 
     if let Some(p) = priority {
@@ -23,10 +33,50 @@ pub fn run(
         }
     }
 
-    if db.update_issue(id, title, description, priority)? {
-        println!("Updated issue #{}", id);
+    if let Some(epic_id) = epic {
+        if db.get_issue(epic_id)?.is_none() {
+            bail!("Epic #{} not found", epic_id);
+        }
+    }
+
+    let estimate_seconds = estimate.map(parse_duration).transpose()?;
+
+    // Confirm every ID exists before touching anything, so a single bad ID can't leave
+    // earlier issues in the batch updated while later ones never got applied.
+    for &id in ids {
+        if db.get_issue(id)?.is_none() {
+            bail!("Issue #{} not found", id);
+        }
+    }
+
+    db.with_transaction(|conn| {
+        for &id in ids {
+            let updated =
+                crate::db::update_issue_on(conn, id, title, description, priority, estimate_seconds)?;
+            if !updated {
+                bail!("Issue #{} not found", id);
+            }
+        }
+        Ok(())
+    })?;
+
+    if ids.len() == 1 {
+        println!("Updated issue #{}", ids[0]);
     } else {
-        bail!("Issue #{} not found", id);
+        let ids_str: Vec<String> = ids.iter().map(|id| format!("#{}", id)).collect();
+        println!("Updated {} issues: {}", ids.len(), ids_str.join(", "));
+    }
+
+    if let Some(epic_id) = epic {
+        for &id in ids {
+            db.attach_to_epic(id, Some(epic_id))?;
+        }
+    }
+
+    if let Some(keep) = keep_history {
+        for &id in ids {
+            db.prune_issue_history(id, keep)?;
+        }
     }
 
     Ok(())
@@ -52,7 +102,7 @@ mod tests {
         let (db, _dir) = setup_test_db();
         let issue_id = db.create_issue("Original title", None, "medium").unwrap();
 
-        let result = run(&db, issue_id, Some("New title"), None, None);
+        let result = run(&db, &[issue_id], Some("New title"), None, None, None, None, None);
         assert!(result.is_ok());
 
         let issue = db.get_issue(issue_id).unwrap().unwrap();
@@ -64,7 +114,7 @@ mod tests {
         let (db, _dir) = setup_test_db();
         let issue_id = db.create_issue("Test", None, "medium").unwrap();
 
-        let result = run(&db, issue_id, None, Some("New description"), None);
+        let result = run(&db, &[issue_id], None, Some("New description"), None, None, None, None);
         assert!(result.is_ok());
 
         let issue = db.get_issue(issue_id).unwrap().unwrap();
@@ -76,7 +126,7 @@ mod tests {
         let (db, _dir) = setup_test_db();
         let issue_id = db.create_issue("Test", None, "medium").unwrap();
 
-        let result = run(&db, issue_id, None, None, Some("critical"));
+        let result = run(&db, &[issue_id], None, None, Some("critical"), None, None, None);
         assert!(result.is_ok());
 
         let issue = db.get_issue(issue_id).unwrap().unwrap();
@@ -90,10 +140,13 @@ mod tests {
 
         let result = run(
             &db,
-            issue_id,
+            &[issue_id],
             Some("New title"),
             Some("New description"),
             Some("high"),
+            None,
+            None,
+            None,
         );
         assert!(result.is_ok());
 
@@ -108,7 +161,7 @@ mod tests {
         let (db, _dir) = setup_test_db();
         let issue_id = db.create_issue("Test", None, "medium").unwrap();
 
-        let result = run(&db, issue_id, None, None, None);
+        let result = run(&db, &[issue_id], None, None, None, None, None, None);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Nothing to update"));
     }
@@ -117,7 +170,7 @@ mod tests {
     fn test_update_nonexistent_issue() {
         let (db, _dir) = setup_test_db();
 
-        let result = run(&db, 99999, Some("New title"), None, None);
+        let result = run(&db, &[99999], Some("New title"), None, None, None, None, None);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not found"));
     }
@@ -127,7 +180,7 @@ mod tests {
         let (db, _dir) = setup_test_db();
         let issue_id = db.create_issue("Test", None, "medium").unwrap();
 
-        let result = run(&db, issue_id, None, None, Some("urgent"));
+        let result = run(&db, &[issue_id], None, None, Some("urgent"), None, None, None);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid priority"));
     }
@@ -140,7 +193,7 @@ mod tests {
             .unwrap();
 
         // Only update title
-        run(&db, issue_id, Some("New title"), None, None).unwrap();
+        run(&db, &[issue_id], Some("New title"), None, None, None, None, None).unwrap();
 
         let issue = db.get_issue(issue_id).unwrap().unwrap();
         assert_eq!(issue.title, "New title");
@@ -153,7 +206,7 @@ mod tests {
         let (db, _dir) = setup_test_db();
         let issue_id = db.create_issue("Original", None, "medium").unwrap();
 
-        let result = run(&db, issue_id, Some("新しいタイトル 🎉"), None, None);
+        let result = run(&db, &[issue_id], Some("新しいタイトル 🎉"), None, None, None, None, None);
         assert!(result.is_ok());
 
         let issue = db.get_issue(issue_id).unwrap().unwrap();
@@ -167,7 +220,7 @@ mod tests {
             .create_issue("Test", Some("Has description"), "medium")
             .unwrap();
 
-        let result = run(&db, issue_id, None, Some(""), None);
+        let result = run(&db, &[issue_id], None, Some(""), None, None, None, None);
         assert!(result.is_ok());
 
         let issue = db.get_issue(issue_id).unwrap().unwrap();
@@ -180,7 +233,7 @@ mod tests {
         let issue_id = db.create_issue("Original", None, "medium").unwrap();
 
         let malicious = "'; DROP TABLE issues; --";
-        let result = run(&db, issue_id, Some(malicious), None, None);
+        let result = run(&db, &[issue_id], Some(malicious), None, None, None, None, None);
         assert!(result.is_ok());
 
         let issue = db.get_issue(issue_id).unwrap().unwrap();
@@ -197,7 +250,7 @@ mod tests {
         let issue_id = db.create_issue("Test", None, "medium").unwrap();
         db.close_issue(issue_id).unwrap();
 
-        let result = run(&db, issue_id, Some("Updated closed issue"), None, None);
+        let result = run(&db, &[issue_id], Some("Updated closed issue"), None, None, None, None, None);
         assert!(result.is_ok());
 
         let issue = db.get_issue(issue_id).unwrap().unwrap();
@@ -205,6 +258,47 @@ mod tests {
         assert_eq!(issue.status, "closed"); // Status should remain closed
     }
 
+    #[test]
+    fn test_update_multiple_ids_atomic() {
+        let (db, _dir) = setup_test_db();
+        let id1 = db.create_issue("Issue 1", None, "low").unwrap();
+        let id2 = db.create_issue("Issue 2", None, "low").unwrap();
+        let id3 = db.create_issue("Issue 3", None, "low").unwrap();
+
+        let result = run(&db, &[id1, id2, id3], None, None, Some("critical"), None, None, None);
+        assert!(result.is_ok());
+
+        for id in [id1, id2, id3] {
+            assert_eq!(db.get_issue(id).unwrap().unwrap().priority, "critical");
+        }
+    }
+
+    #[test]
+    fn test_update_multiple_ids_rolls_back_on_missing() {
+        let (db, _dir) = setup_test_db();
+        let id1 = db.create_issue("Issue 1", None, "low").unwrap();
+        let id2 = db.create_issue("Issue 2", None, "low").unwrap();
+
+        let result = run(&db, &[id1, 99999, id2], None, None, Some("critical"), None, None, None);
+        assert!(result.is_err());
+
+        // Neither issue should have been updated, even though id1 comes before the bad ID
+        assert_eq!(db.get_issue(id1).unwrap().unwrap().priority, "low");
+        assert_eq!(db.get_issue(id2).unwrap().unwrap().priority, "low");
+    }
+
+    #[test]
+    fn test_update_keep_history_prunes() {
+        let (db, _dir) = setup_test_db();
+        let id = db.create_issue("v0", None, "medium").unwrap();
+        for i in 1..=5 {
+            run(&db, &[id], Some(&format!("v{}", i)), None, None, None, None, Some(2)).unwrap();
+        }
+
+        let entries = db.get_issue_history(id).unwrap();
+        assert_eq!(entries.len(), 2);
+    }
+
     // ==================== Property-Based Tests ====================
 
     proptest! {
@@ -216,7 +310,7 @@ mod tests {
             let (db, _dir) = setup_test_db();
             let issue_id = db.create_issue(&original, None, "medium").unwrap();
 
-            run(&db, issue_id, Some(&new_title), None, None).unwrap();
+            run(&db, &[issue_id], Some(&new_title), None, None, None, None, None).unwrap();
 
             let issue = db.get_issue(issue_id).unwrap().unwrap();
             prop_assert_eq!(issue.title, new_title);
@@ -227,7 +321,7 @@ mod tests {
             let (db, _dir) = setup_test_db();
             let issue_id = db.create_issue("Test", None, "medium").unwrap();
 
-            let result = run(&db, issue_id, None, None, Some(&priority));
+            let result = run(&db, &[issue_id], None, None, Some(&priority), None, None, None);
             prop_assert!(result.is_ok());
 
             let issue = db.get_issue(issue_id).unwrap().unwrap();
@@ -244,7 +338,7 @@ mod tests {
             let (db, _dir) = setup_test_db();
             let issue_id = db.create_issue("Test", None, "medium").unwrap();
 
-            let result = run(&db, issue_id, None, None, Some(&priority));
+            let result = run(&db, &[issue_id], None, None, Some(&priority), None, None, None);
             prop_assert!(result.is_err());
         }
 
@@ -252,7 +346,7 @@ mod tests {
         fn prop_nonexistent_issue_fails(issue_id in 1000i64..10000) {
             let (db, _dir) = setup_test_db();
 
-            let result = run(&db, issue_id, Some("New title"), None, None);
+            let result = run(&db, &[issue_id], Some("New title"), None, None, None, None, None);
             prop_assert!(result.is_err());
         }
 
@@ -261,7 +355,7 @@ mod tests {
             let (db, _dir) = setup_test_db();
             let issue_id = db.create_issue("Test", None, "medium").unwrap();
 
-            run(&db, issue_id, None, Some(&desc), None).unwrap();
+            run(&db, &[issue_id], None, Some(&desc), None, None, None, None).unwrap();
 
             let issue = db.get_issue(issue_id).unwrap().unwrap();
             prop_assert_eq!(issue.description, Some(desc));
@@ -0,0 +1,275 @@
+//! Backs up `tested::run`'s "Test reminder will reset on next code change" promise: polls the
+//! project's source files for changes made after the `last_test_run` marker, invalidates the
+//! marker the moment it sees one, and reuses `next::run`'s scoring so the user immediately sees
+//! what to work on. There's no filesystem-event crate available in this tree, so the loop polls
+//! mtimes on a fixed interval instead.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::commands::next;
+use crate::db::Database;
+
+/// Directories never worth descending into when collecting tracked source files.
+const IGNORED_DIRS: [&str; 7] =
+    [".git", ".chainlink", ".claude", "target", "node_modules", "dist", "build"];
+
+/// Extensions `watch` treats as source files worth triggering a test reminder over.
+const TRACKED_EXTENSIONS: [&str; 11] =
+    ["rs", "ts", "tsx", "js", "jsx", "py", "go", "rb", "java", "c", "cpp"];
+
+/// How long a burst of saves must go quiet before `watch` acts on it, so saving a dozen files
+/// in an editor's "format on save" pass produces one reminder instead of a dozen.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Recursively collects every tracked source file under `root`, skipping `IGNORED_DIRS`.
+fn collect_tracked_files(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    collect_into(root, &mut files)?;
+    Ok(files)
+}
+
+fn collect_into(dir: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    let entries =
+        fs::read_dir(dir).with_context(|| format!("Failed to read directory {}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            let ignored = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|name| IGNORED_DIRS.contains(&name))
+                .unwrap_or(false);
+            if !ignored {
+                collect_into(&path, out)?;
+            }
+        } else if is_tracked(&path) {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn is_tracked(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|ext| TRACKED_EXTENSIONS.contains(&ext))
+        .unwrap_or(false)
+}
+
+/// Latest mtime among `files`, or `None` if there are no tracked files (or none have readable
+/// metadata).
+fn latest_mtime(files: &[PathBuf]) -> Option<SystemTime> {
+    files.iter().filter_map(|f| fs::metadata(f).ok()?.modified().ok()).max()
+}
+
+/// Mtime of the `last_test_run` marker `tested::run` writes, or `None` if tests have never been
+/// marked as run (or the marker was already invalidated).
+fn marker_mtime(chainlink_dir: &Path) -> Option<SystemTime> {
+    fs::metadata(chainlink_dir.join("last_test_run")).ok()?.modified().ok()
+}
+
+/// Deletes the `last_test_run` marker so anything checking for its existence sees tests as due
+/// again. A no-op if it's already gone.
+fn invalidate_marker(chainlink_dir: &Path) -> Result<()> {
+    let marker = chainlink_dir.join("last_test_run");
+    if marker.exists() {
+        fs::remove_file(&marker).context("Failed to clear stale test marker")?;
+    }
+    Ok(())
+}
+
+/// Whether `latest_change` is newer than the test marker — also true when there's no marker at
+/// all (tests have simply never been run against the current tree).
+fn is_stale(latest_change: Option<SystemTime>, marker: Option<SystemTime>) -> bool {
+    match (latest_change, marker) {
+        (Some(changed), Some(tested)) => changed > tested,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+/// Scans `project_root` once. If tracked files changed after the test marker, invalidates it,
+/// prints a reminder, and shows the `next` recommendation. Returns whether it did so.
+fn scan_once(project_root: &Path, chainlink_dir: &Path, db: &Database) -> Result<bool> {
+    let files = collect_tracked_files(project_root)?;
+    let stale = is_stale(latest_mtime(&files), marker_mtime(chainlink_dir));
+
+    if stale {
+        invalidate_marker(chainlink_dir)?;
+        println!("Code changed since the last test run — tests are due.");
+        println!();
+        next::run(db)?;
+    }
+
+    Ok(stale)
+}
+
+/// `chainlink watch`'s entry point. With `once`, scans exactly once and returns — for use from
+/// hooks (e.g. a pre-commit check) that want a single pass rather than a long-running process.
+/// Otherwise polls `project_root` until interrupted, debouncing bursts of saves into a single
+/// reminder per change.
+pub fn run(chainlink_dir: &Path, db: &Database, once: bool) -> Result<()> {
+    let project_root = chainlink_dir.parent().unwrap_or(chainlink_dir);
+
+    if once {
+        if !scan_once(project_root, chainlink_dir, db)? {
+            println!("No code changes since the last test run.");
+        }
+        return Ok(());
+    }
+
+    println!("Watching {} for code changes (Ctrl+C to stop)...", project_root.display());
+
+    // When the current burst was first seen, and the latest mtime at that point.
+    let mut pending_since: Option<(Instant, Option<SystemTime>)> = None;
+    let mut handled_change: Option<SystemTime> = None;
+
+    loop {
+        let files = collect_tracked_files(project_root)?;
+        let latest_change = latest_mtime(&files);
+        let marker = marker_mtime(chainlink_dir);
+
+        if is_stale(latest_change, marker) && handled_change != latest_change {
+            match pending_since {
+                Some((since, seen)) if seen == latest_change && since.elapsed() >= DEBOUNCE => {
+                    invalidate_marker(chainlink_dir)?;
+                    println!("Code changed — tests are due.");
+                    println!();
+                    next::run(db)?;
+                    handled_change = latest_change;
+                    pending_since = None;
+                }
+                Some((_, seen)) if seen == latest_change => {}
+                // First sighting, or another save moved the latest mtime: restart the quiet period.
+                _ => pending_since = Some((Instant::now(), latest_change)),
+            }
+        } else if !is_stale(latest_change, marker) {
+            pending_since = None;
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn touch(path: &Path) {
+        fs::write(path, "").unwrap();
+    }
+
+    #[test]
+    fn test_collect_tracked_files_finds_supported_extensions() {
+        let dir = tempdir().unwrap();
+        touch(&dir.path().join("main.rs"));
+        touch(&dir.path().join("notes.txt"));
+
+        let files = collect_tracked_files(dir.path()).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].file_name().unwrap(), "main.rs");
+    }
+
+    #[test]
+    fn test_collect_tracked_files_skips_ignored_dirs() {
+        let dir = tempdir().unwrap();
+        let target = dir.path().join("target");
+        fs::create_dir_all(&target).unwrap();
+        touch(&target.join("build.rs"));
+        touch(&dir.path().join("lib.rs"));
+
+        let files = collect_tracked_files(dir.path()).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].file_name().unwrap(), "lib.rs");
+    }
+
+    #[test]
+    fn test_collect_tracked_files_recurses_into_subdirs() {
+        let dir = tempdir().unwrap();
+        let nested = dir.path().join("src").join("commands");
+        fs::create_dir_all(&nested).unwrap();
+        touch(&nested.join("watch.rs"));
+
+        let files = collect_tracked_files(dir.path()).unwrap();
+        assert_eq!(files.len(), 1);
+    }
+
+    #[test]
+    fn test_is_stale_true_when_no_marker_exists() {
+        assert!(is_stale(Some(SystemTime::now()), None));
+    }
+
+    #[test]
+    fn test_is_stale_false_when_no_tracked_files() {
+        assert!(!is_stale(None, None));
+    }
+
+    #[test]
+    fn test_is_stale_compares_mtimes() {
+        let earlier = SystemTime::UNIX_EPOCH;
+        let later = earlier + Duration::from_secs(60);
+        assert!(is_stale(Some(later), Some(earlier)));
+        assert!(!is_stale(Some(earlier), Some(later)));
+    }
+
+    #[test]
+    fn test_invalidate_marker_removes_existing_file() {
+        let dir = tempdir().unwrap();
+        let chainlink_dir = dir.path().join(".chainlink");
+        fs::create_dir_all(&chainlink_dir).unwrap();
+        touch(&chainlink_dir.join("last_test_run"));
+
+        invalidate_marker(&chainlink_dir).unwrap();
+        assert!(!chainlink_dir.join("last_test_run").exists());
+    }
+
+    #[test]
+    fn test_invalidate_marker_is_a_noop_without_one() {
+        let dir = tempdir().unwrap();
+        let chainlink_dir = dir.path().join(".chainlink");
+        fs::create_dir_all(&chainlink_dir).unwrap();
+
+        assert!(invalidate_marker(&chainlink_dir).is_ok());
+    }
+
+    #[test]
+    fn test_scan_once_invalidates_marker_on_newer_source_file() {
+        let dir = tempdir().unwrap();
+        let chainlink_dir = dir.path().join(".chainlink");
+        fs::create_dir_all(&chainlink_dir).unwrap();
+        let db_path = chainlink_dir.join("issues.db");
+        let db = Database::open(&db_path).unwrap();
+
+        touch(&chainlink_dir.join("last_test_run"));
+        thread::sleep(Duration::from_millis(10));
+        touch(&dir.path().join("main.rs"));
+
+        let stale = scan_once(dir.path(), &chainlink_dir, &db).unwrap();
+        assert!(stale);
+        assert!(!chainlink_dir.join("last_test_run").exists());
+    }
+
+    #[test]
+    fn test_scan_once_reports_clean_when_marker_is_newest() {
+        let dir = tempdir().unwrap();
+        let chainlink_dir = dir.path().join(".chainlink");
+        fs::create_dir_all(&chainlink_dir).unwrap();
+        let db_path = chainlink_dir.join("issues.db");
+        let db = Database::open(&db_path).unwrap();
+
+        touch(&dir.path().join("main.rs"));
+        thread::sleep(Duration::from_millis(10));
+        // The marker is written after the source file, so it's up to date.
+        touch(&chainlink_dir.join("last_test_run"));
+
+        let stale = scan_once(dir.path(), &chainlink_dir, &db).unwrap();
+        assert!(!stale);
+        assert!(chainlink_dir.join("last_test_run").exists());
+    }
+}
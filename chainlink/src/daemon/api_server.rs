@@ -0,0 +1,35 @@
+//! The admin API's connection loop: accepts TCP connections and hands each to
+//! `router::handle` on its own thread. Parsing and routing live in `router`; this module is
+//! only concerned with accepting connections and not letting one bad request take the
+//! server down.
+
+use anyhow::{Context, Result};
+use std::net::{TcpListener, TcpStream};
+
+use crate::db::Database;
+
+use super::router;
+
+pub fn serve(addr: &str, db: Database) -> Result<()> {
+    let listener = TcpListener::bind(addr)
+        .with_context(|| format!("Failed to bind admin API to {}", addr))?;
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(_) => continue,
+        };
+        let db = db.clone();
+        std::thread::spawn(move || handle_connection(stream, &db));
+    }
+
+    Ok(())
+}
+
+/// A malformed request or a handler error becomes a response on the wire, not a dropped
+/// connection or a crashed thread — one misbehaving client shouldn't take the API down.
+fn handle_connection(stream: TcpStream, db: &Database) {
+    if let Err(err) = router::handle(stream, db) {
+        eprintln!("admin API: {:#}", err);
+    }
+}
@@ -0,0 +1,161 @@
+//! Background daemon management: `start`/`stop`/`status` manage a detached child process
+//! running `run_daemon` (spawned via the hidden `daemon run` subcommand), tracked by a pid
+//! file in the `.chainlink` directory. The daemon itself just idles unless `--listen` is
+//! given, in which case it hosts the read/write admin API in `api_server`/`router`.
+
+mod api_server;
+mod router;
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use crate::db::{Database, DatabaseOptions};
+
+const PID_FILE: &str = "daemon.pid";
+const LOG_FILE: &str = "daemon.log";
+
+/// Spawns a detached `chainlink daemon run` process and records its pid (and, if given, the
+/// admin API's listen address) in `daemon.pid`.
+pub fn start(chainlink_dir: &Path, listen: Option<&str>) -> Result<()> {
+    let pid_path = chainlink_dir.join(PID_FILE);
+    if let Some(pid) = running_pid(&pid_path) {
+        bail!("Daemon is already running (pid {})", pid);
+    }
+
+    let exe = env::current_exe().context("Failed to locate the chainlink executable")?;
+    let log = fs::File::create(chainlink_dir.join(LOG_FILE))
+        .context("Failed to create daemon log file")?;
+
+    let mut cmd = Command::new(exe);
+    cmd.arg("daemon")
+        .arg("run")
+        .arg("--dir")
+        .arg(chainlink_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(log.try_clone().context("Failed to duplicate daemon log handle")?))
+        .stderr(Stdio::from(log));
+    if let Some(addr) = listen {
+        cmd.arg("--listen").arg(addr);
+    }
+
+    let child = cmd.spawn().context("Failed to spawn daemon process")?;
+    fs::write(&pid_path, format!("{}\n{}\n", child.id(), listen.unwrap_or("")))
+        .context("Failed to write daemon pid file")?;
+
+    println!("Daemon started (pid {})", child.id());
+    if let Some(addr) = listen {
+        println!("Admin API listening on {}", addr);
+    }
+    Ok(())
+}
+
+/// Sends `SIGTERM` to the running daemon and removes its pid file.
+pub fn stop(chainlink_dir: &Path) -> Result<()> {
+    let pid_path = chainlink_dir.join(PID_FILE);
+    let pid = running_pid(&pid_path).ok_or_else(|| anyhow!("Daemon is not running"))?;
+
+    signal(pid, "TERM")?;
+    fs::remove_file(&pid_path).ok();
+    println!("Daemon stopped (pid {})", pid);
+    Ok(())
+}
+
+/// Reports whether the daemon is running and, if it is, the admin API's listen address.
+pub fn status(chainlink_dir: &Path) -> Result<()> {
+    let pid_path = chainlink_dir.join(PID_FILE);
+    match running_pid(&pid_path) {
+        Some(pid) => {
+            println!("Daemon is running (pid {})", pid);
+            if let Some(addr) = listen_addr(&pid_path) {
+                println!("Admin API listening on {}", addr);
+            }
+        }
+        None => println!("Daemon is not running"),
+    }
+    Ok(())
+}
+
+/// The daemon's actual body, run by the hidden `daemon run --dir DIR` subcommand. Opens its
+/// own wider connection pool (per `DatabaseOptions`'s doc comment, this is what a longer-running
+/// process should do instead of the CLI's default-sized pool) and, if `listen` is set, blocks
+/// serving the admin API; otherwise it just idles until killed by `stop`.
+pub fn run_daemon(dir: &Path, listen: Option<&str>) -> Result<()> {
+    let db_path = dir.join("issues.db");
+    let db = Database::open_with_options(
+        &db_path,
+        DatabaseOptions {
+            pool_size: 16,
+            ..DatabaseOptions::default()
+        },
+    )
+    .context("Failed to open database")?;
+
+    match listen {
+        Some(addr) => api_server::serve(addr, db),
+        None => loop {
+            thread::sleep(Duration::from_secs(60));
+        },
+    }
+}
+
+/// Reads the pid recorded in `pid_path`, but only if that process is still alive — a stale
+/// pid file (left behind by a daemon that crashed or was killed with `kill -9`) reads back as
+/// "not running" rather than blocking a future `start`.
+fn running_pid(pid_path: &Path) -> Option<u32> {
+    let pid: u32 = fs::read_to_string(pid_path)
+        .ok()?
+        .lines()
+        .next()?
+        .trim()
+        .parse()
+        .ok()?;
+    process_alive(pid).then_some(pid)
+}
+
+fn listen_addr(pid_path: &Path) -> Option<String> {
+    fs::read_to_string(pid_path)
+        .ok()?
+        .lines()
+        .nth(1)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    Command::new("kill")
+        .arg("-0")
+        .arg(pid.to_string())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn process_alive(_pid: u32) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn signal(pid: u32, signal: &str) -> Result<()> {
+    let status = Command::new("kill")
+        .arg(format!("-{}", signal))
+        .arg(pid.to_string())
+        .status()
+        .context("Failed to send signal to daemon process")?;
+    if !status.success() {
+        bail!("Failed to stop daemon (pid {})", pid);
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn signal(_pid: u32, _signal: &str) -> Result<()> {
+    bail!("Stopping the daemon is only supported on Unix");
+}
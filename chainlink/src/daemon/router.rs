@@ -0,0 +1,260 @@
+//! Routes parsed HTTP requests to handler functions that reuse `db::Database` and
+//! `commands::*` validation, returning the same `ExportedIssue` JSON shape `chainlink export
+//! --format json` produces. Deliberately hand-rolled rather than pulled in from a web
+//! framework: the whole surface is seven routes behind an opt-in flag, and `chainlink` otherwise
+//! has no HTTP dependency at all.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+
+use crate::commands::create::{validate_issue_type, validate_priority};
+use crate::commands::export::export_issue;
+use crate::commands::timer::parse_duration;
+use crate::db::Database;
+
+struct HttpRequest {
+    method: String,
+    segments: Vec<String>,
+    query: HashMap<String, String>,
+    body: Vec<u8>,
+}
+
+pub fn handle(stream: TcpStream, db: &Database) -> Result<()> {
+    let mut reader = BufReader::new(stream.try_clone().context("Failed to clone connection")?);
+    let (status, body) = match read_request(&mut reader) {
+        Ok(request) => route(db, &request),
+        Err(_) => (400, json!({"error": "Malformed request"})),
+    };
+    write_response(stream, status, &body)
+}
+
+fn route(db: &Database, req: &HttpRequest) -> (u16, Value) {
+    let segments: Vec<&str> = req.segments.iter().map(String::as_str).collect();
+    let outcome = match (req.method.as_str(), segments.as_slice()) {
+        ("GET", ["issues"]) => list_issues(db, req),
+        ("GET", ["issues", id]) => get_issue(db, id),
+        ("POST", ["issues"]) => create_issue(db, req),
+        ("POST", ["issues", id, "comments"]) => add_comment(db, id, req),
+        ("POST", ["issues", id, "block"]) => block_issue(db, id, req),
+        ("GET", ["ready"]) => list_ready(db),
+        ("GET", ["blocked"]) => list_blocked(db),
+        _ => return (404, json!({"error": "Not found"})),
+    };
+
+    outcome.unwrap_or_else(|err| (500, json!({"error": err.to_string()})))
+}
+
+fn list_issues(db: &Database, req: &HttpRequest) -> Result<(u16, Value)> {
+    let status = req.query.get("status").map(String::as_str);
+    let label = req.query.get("label").map(String::as_str);
+    let priority = req.query.get("priority").map(String::as_str);
+
+    let issues = db.list_issues(status, label, priority)?;
+    Ok((200, exported_list(db, &issues)?))
+}
+
+fn get_issue(db: &Database, id: &str) -> Result<(u16, Value)> {
+    let Some(id) = parse_id(id) else {
+        return Ok((400, json!({"error": "Invalid issue id"})));
+    };
+    match db.get_issue(id)? {
+        Some(issue) => Ok((200, json!(export_issue(db, &issue)?))),
+        None => Ok((404, json!({"error": format!("Issue #{} not found", id)}))),
+    }
+}
+
+#[derive(Deserialize)]
+struct CreateIssueBody {
+    title: String,
+    description: Option<String>,
+    #[serde(default = "default_priority")]
+    priority: String,
+    estimate: Option<String>,
+    issue_type: Option<String>,
+    epic_id: Option<i64>,
+}
+
+fn default_priority() -> String {
+    "medium".to_string()
+}
+
+fn create_issue(db: &Database, req: &HttpRequest) -> Result<(u16, Value)> {
+    let body: CreateIssueBody = match serde_json::from_slice(&req.body) {
+        Ok(body) => body,
+        Err(err) => return Ok((400, json!({"error": format!("Invalid request body: {}", err)}))),
+    };
+
+    if !validate_priority(&body.priority) {
+        return Ok((400, json!({"error": format!("Invalid priority '{}'", body.priority)})));
+    }
+    if let Some(issue_type) = &body.issue_type {
+        if !validate_issue_type(issue_type) {
+            return Ok((400, json!({"error": format!("Invalid issue type '{}'", issue_type)})));
+        }
+    }
+    if let Some(epic_id) = body.epic_id {
+        if db.get_issue(epic_id)?.is_none() {
+            return Ok((404, json!({"error": format!("Epic #{} not found", epic_id)})));
+        }
+    }
+    let estimate_seconds = match body.estimate.as_deref().map(parse_duration).transpose() {
+        Ok(seconds) => seconds,
+        Err(err) => return Ok((400, json!({"error": err.to_string()}))),
+    };
+
+    let id = db.create_issue(&body.title, body.description.as_deref(), &body.priority)?;
+    if let Some(seconds) = estimate_seconds {
+        db.set_estimate(id, Some(seconds))?;
+    }
+    if let Some(issue_type) = &body.issue_type {
+        db.set_issue_type(id, issue_type)?;
+    }
+    if let Some(epic_id) = body.epic_id {
+        db.attach_to_epic(id, Some(epic_id))?;
+    }
+
+    let issue = db.get_issue(id)?.context("Just-created issue vanished")?;
+    Ok((201, json!(export_issue(db, &issue)?)))
+}
+
+#[derive(Deserialize)]
+struct AddCommentBody {
+    content: String,
+}
+
+fn add_comment(db: &Database, id: &str, req: &HttpRequest) -> Result<(u16, Value)> {
+    let Some(id) = parse_id(id) else {
+        return Ok((400, json!({"error": "Invalid issue id"})));
+    };
+    if db.get_issue(id)?.is_none() {
+        return Ok((404, json!({"error": format!("Issue #{} not found", id)})));
+    }
+    let body: AddCommentBody = match serde_json::from_slice(&req.body) {
+        Ok(body) => body,
+        Err(err) => return Ok((400, json!({"error": format!("Invalid request body: {}", err)}))),
+    };
+
+    let comment_id = db.add_comment(id, &body.content)?;
+    Ok((201, json!({"id": comment_id})))
+}
+
+#[derive(Deserialize)]
+struct BlockBody {
+    blocker_id: i64,
+}
+
+fn block_issue(db: &Database, id: &str, req: &HttpRequest) -> Result<(u16, Value)> {
+    let Some(id) = parse_id(id) else {
+        return Ok((400, json!({"error": "Invalid issue id"})));
+    };
+    let body: BlockBody = match serde_json::from_slice(&req.body) {
+        Ok(body) => body,
+        Err(err) => return Ok((400, json!({"error": format!("Invalid request body: {}", err)}))),
+    };
+
+    if db.get_issue(id)?.is_none() {
+        return Ok((404, json!({"error": format!("Issue #{} not found", id)})));
+    }
+    if db.get_issue(body.blocker_id)?.is_none() {
+        return Ok((404, json!({"error": format!("Issue #{} not found", body.blocker_id)})));
+    }
+    if id == body.blocker_id {
+        return Ok((400, json!({"error": "An issue cannot block itself"})));
+    }
+
+    let created = db.add_dependency(id, body.blocker_id)?;
+    Ok((200, json!({"blocked": created})))
+}
+
+fn list_ready(db: &Database) -> Result<(u16, Value)> {
+    let issues = db.list_ready_issues()?;
+    Ok((200, exported_list(db, &issues)?))
+}
+
+fn list_blocked(db: &Database) -> Result<(u16, Value)> {
+    let issues = db.list_blocked_issues()?;
+    Ok((200, exported_list(db, &issues)?))
+}
+
+fn exported_list(db: &Database, issues: &[crate::models::Issue]) -> Result<Value> {
+    let exported = issues
+        .iter()
+        .map(|issue| export_issue(db, issue))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(json!(exported))
+}
+
+fn parse_id(raw: &str) -> Option<i64> {
+    raw.parse().ok()
+}
+
+fn read_request(reader: &mut BufReader<TcpStream>) -> Result<HttpRequest> {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().context("Missing HTTP method")?.to_string();
+    let target = parts.next().context("Missing request target")?.to_string();
+
+    let mut content_length = 0usize;
+    loop {
+        let mut header_line = String::new();
+        if reader.read_line(&mut header_line)? == 0 {
+            break;
+        }
+        let header_line = header_line.trim_end();
+        if header_line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header_line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+
+    let (path, query_string) = target.split_once('?').unwrap_or((target.as_str(), ""));
+    let segments = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect();
+    let query = query_string
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+    Ok(HttpRequest { method, segments, query, body })
+}
+
+fn write_response(mut stream: TcpStream, status: u16, body: &Value) -> Result<()> {
+    let payload = serde_json::to_vec(body)?;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        status_text(status),
+        payload.len()
+    )?;
+    stream.write_all(&payload)?;
+    Ok(())
+}
+
+fn status_text(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        404 => "Not Found",
+        500 => "Internal Server Error",
+        _ => "Unknown",
+    }
+}
@@ -1,126 +1,239 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use chrono::{DateTime, Utc};
-use rusqlite::{params, Connection};
-use std::path::Path;
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::crypto::{self, KeyManager, PassphraseKeyManager};
+use crate::migrations;
+use crate::models::{
+    Comment, HealthIssue, HealthReport, HistoryEntry, Issue, Milestone, SearchHit, Session,
+    TimeEntry,
+};
+
+const VALID_PRIORITIES: [&str; 4] = ["low", "medium", "high", "critical"];
+const VALID_STATUSES: [&str; 2] = ["open", "closed"];
+const DEFAULT_PRIORITY: &str = "medium";
+
+/// A connection checked out of `Database`'s pool. Derefs to `rusqlite::Connection`, so it's
+/// usable anywhere `&Connection` is expected (e.g. `unchecked_transaction`, or passing to
+/// free functions like `update_issue_on`).
+pub type PooledConnection = r2d2::PooledConnection<SqliteConnectionManager>;
+
+/// Tuning knobs for `Database::open_with_options`. `Database::open` uses `Default::default()`,
+/// which is the right choice for the CLI's own usage; callers embedding chainlink alongside a
+/// longer-running process (e.g. the daemon) can widen the pool or relax the pragmas.
+#[derive(Debug, Clone)]
+pub struct DatabaseOptions {
+    /// Max number of pooled connections. Readers (list/show/search) and writers alike draw
+    /// from this pool; WAL mode is what lets them run concurrently instead of blocking.
+    pub pool_size: u32,
+    /// `PRAGMA busy_timeout`, in milliseconds, so a writer contending with another connection
+    /// retries instead of immediately erroring with `SQLITE_BUSY`.
+    pub busy_timeout_ms: u32,
+    /// `PRAGMA mmap_size`, in bytes.
+    pub mmap_size_bytes: i64,
+    /// When set, issued as `PRAGMA key` on every pooled connection immediately after it's
+    /// opened (before any other pragma), encrypting the database file at rest via SQLCipher.
+    /// `None` (the default) opens a plain, unencrypted database.
+    pub encryption_key: Option<EncryptionKey>,
+}
 
-use crate::models::{Comment, Issue, Session};
+impl Default for DatabaseOptions {
+    fn default() -> Self {
+        DatabaseOptions {
+            pool_size: 8,
+            busy_timeout_ms: 5_000,
+            mmap_size_bytes: 256 * 1024 * 1024,
+            encryption_key: None,
+        }
+    }
+}
 
-const SCHEMA_VERSION: i32 = 3;
+/// The two forms of key `DatabaseOptions::encryption_key` accepts for `PRAGMA key`.
+#[derive(Debug, Clone)]
+pub enum EncryptionKey {
+    /// A user-supplied passphrase, run through SQLCipher's own PBKDF2 key derivation.
+    Passphrase(String),
+    /// An already-derived 256-bit key, passed straight through as a raw hex literal so
+    /// SQLCipher doesn't derive a second key on top of it. This is what `open_encrypted` uses:
+    /// the real key is the DEK unwrapped from the `.key` sidecar, not `passphrase` itself.
+    Raw([u8; crypto::DEK_LEN]),
+}
 
+/// Cheap to clone: `Pool` is an `Arc` internally, so every clone shares the same pool of
+/// connections. The daemon's admin API relies on this to hand each request-handling thread
+/// its own `Database` without re-opening the file.
+#[derive(Clone)]
 pub struct Database {
-    conn: Connection,
+    pool: Pool<SqliteConnectionManager>,
 }
 
 impl Database {
     pub fn open(path: &Path) -> Result<Self> {
-        let conn = Connection::open(path).context("Failed to open database")?;
-        let db = Database { conn };
+        Self::open_with_options(path, DatabaseOptions::default())
+    }
+
+    /// Opens (or creates) an encrypted database using AES Key Wrap (RFC 3394), modeled on
+    /// CouchDB aegis's key-wrap design: `passphrase` only ever derives a key-encryption key
+    /// (KEK), never the key SQLCipher actually encrypts pages with. The real key is a random
+    /// 256-bit data-encryption key (DEK), generated on first open and wrapped under the KEK
+    /// in a `<path>.key` sidecar file next to the database (see `load_or_create_dek`). Every
+    /// pooled connection gets the unwrapped DEK via `PRAGMA key` before anything else touches
+    /// the file, so the whole database — schema, indexes, and all — is encrypted at rest.
+    ///
+    /// This indirection is what lets `rotate_passphrase` rewrap the same DEK under a new KEK,
+    /// changing the password without re-encrypting a single page.
+    pub fn open_encrypted(path: &Path, passphrase: &str) -> Result<Self> {
+        Self::open_encrypted_with(path, &PassphraseKeyManager { passphrase: passphrase.to_string() })
+    }
+
+    /// Same as `open_encrypted`, but takes any `KeyManager` instead of assuming a passphrase —
+    /// e.g. `NoopKeyManager` to exercise the envelope-encryption path without one.
+    pub fn open_encrypted_with(path: &Path, key_manager: &dyn KeyManager) -> Result<Self> {
+        let dek = Self::load_or_create_dek(path, key_manager)?;
+        Self::open_with_options(
+            path,
+            DatabaseOptions {
+                encryption_key: Some(EncryptionKey::Raw(dek)),
+                ..DatabaseOptions::default()
+            },
+        )
+    }
+
+    /// Builds the pool, applying WAL journaling and the rest of `options` to every connection
+    /// it hands out (via `SqliteConnectionManager::with_init`), then runs schema migrations
+    /// once on a connection drawn from that pool.
+    pub fn open_with_options(path: &Path, options: DatabaseOptions) -> Result<Self> {
+        let busy_timeout_ms = options.busy_timeout_ms;
+        let mmap_size_bytes = options.mmap_size_bytes;
+        let encryption_key = options.encryption_key.clone();
+
+        let manager = SqliteConnectionManager::file(path).with_init(move |conn| {
+            if let Some(key) = &encryption_key {
+                let key_literal = match key {
+                    EncryptionKey::Passphrase(passphrase) => {
+                        format!("'{}'", crypto::escape_sql_literal(passphrase))
+                    }
+                    EncryptionKey::Raw(dek) => format!("\"x'{}'\"", crypto::hex_encode(dek)),
+                };
+                conn.execute_batch(&format!("PRAGMA key = {};", key_literal))?;
+            }
+            conn.execute_batch(&format!(
+                "PRAGMA foreign_keys = ON;
+                 PRAGMA journal_mode = WAL;
+                 PRAGMA synchronous = NORMAL;
+                 PRAGMA busy_timeout = {};
+                 PRAGMA mmap_size = {};",
+                busy_timeout_ms, mmap_size_bytes
+            ))
+        });
+
+        let pool = Pool::builder()
+            .max_size(options.pool_size)
+            .build(manager)
+            .context("Failed to build connection pool")?;
+
+        let db = Database { pool };
         db.init_schema()?;
         Ok(db)
     }
 
-    fn init_schema(&self) -> Result<()> {
-        // Check if we need to initialize
-        let version: i32 = self
-            .conn
-            .query_row(
-                "SELECT COALESCE(MAX(version), 0) FROM pragma_user_version",
-                [],
-                |row| row.get(0),
-            )
-            .unwrap_or(0);
+    /// Rekeys an encrypted database in place via SQLCipher's `PRAGMA rekey`, re-encrypting
+    /// every page under `new_passphrase` directly. Only meaningful for a database opened with
+    /// `EncryptionKey::Passphrase` rather than `open_encrypted`'s DEK/KEK scheme — for that,
+    /// use the much cheaper `rotate_passphrase` instead, which never touches a page.
+    pub fn change_passphrase(&self, new_passphrase: &str) -> Result<()> {
+        self.conn()?.execute_batch(&format!(
+            "PRAGMA rekey = '{}';",
+            crypto::escape_sql_literal(new_passphrase)
+        ))?;
+        Ok(())
+    }
 
-        if version < SCHEMA_VERSION {
-            self.conn.execute_batch(
-                r#"
-                -- Core issues table
-                CREATE TABLE IF NOT EXISTS issues (
-                    id INTEGER PRIMARY KEY AUTOINCREMENT,
-                    title TEXT NOT NULL,
-                    description TEXT,
-                    status TEXT NOT NULL DEFAULT 'open',
-                    priority TEXT NOT NULL DEFAULT 'medium',
-                    parent_id INTEGER,
-                    created_at TEXT NOT NULL,
-                    updated_at TEXT NOT NULL,
-                    closed_at TEXT,
-                    FOREIGN KEY (parent_id) REFERENCES issues(id) ON DELETE CASCADE
-                );
-
-                -- Labels (many-to-many)
-                CREATE TABLE IF NOT EXISTS labels (
-                    issue_id INTEGER NOT NULL,
-                    label TEXT NOT NULL,
-                    PRIMARY KEY (issue_id, label),
-                    FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
-                );
-
-                -- Dependencies (blocker blocks blocked)
-                CREATE TABLE IF NOT EXISTS dependencies (
-                    blocker_id INTEGER NOT NULL,
-                    blocked_id INTEGER NOT NULL,
-                    PRIMARY KEY (blocker_id, blocked_id),
-                    FOREIGN KEY (blocker_id) REFERENCES issues(id) ON DELETE CASCADE,
-                    FOREIGN KEY (blocked_id) REFERENCES issues(id) ON DELETE CASCADE
-                );
-
-                -- Comments
-                CREATE TABLE IF NOT EXISTS comments (
-                    id INTEGER PRIMARY KEY AUTOINCREMENT,
-                    issue_id INTEGER NOT NULL,
-                    content TEXT NOT NULL,
-                    created_at TEXT NOT NULL,
-                    FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
-                );
-
-                -- Sessions (for context preservation)
-                CREATE TABLE IF NOT EXISTS sessions (
-                    id INTEGER PRIMARY KEY AUTOINCREMENT,
-                    started_at TEXT NOT NULL,
-                    ended_at TEXT,
-                    active_issue_id INTEGER,
-                    handoff_notes TEXT,
-                    FOREIGN KEY (active_issue_id) REFERENCES issues(id)
-                );
-
-                -- Time tracking
-                CREATE TABLE IF NOT EXISTS time_entries (
-                    id INTEGER PRIMARY KEY AUTOINCREMENT,
-                    issue_id INTEGER NOT NULL,
-                    started_at TEXT NOT NULL,
-                    ended_at TEXT,
-                    duration_seconds INTEGER,
-                    FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
-                );
-
-                -- Indexes
-                CREATE INDEX IF NOT EXISTS idx_issues_status ON issues(status);
-                CREATE INDEX IF NOT EXISTS idx_issues_priority ON issues(priority);
-                CREATE INDEX IF NOT EXISTS idx_labels_issue ON labels(issue_id);
-                CREATE INDEX IF NOT EXISTS idx_comments_issue ON comments(issue_id);
-                CREATE INDEX IF NOT EXISTS idx_deps_blocker ON dependencies(blocker_id);
-                CREATE INDEX IF NOT EXISTS idx_deps_blocked ON dependencies(blocked_id);
-                CREATE INDEX IF NOT EXISTS idx_issues_parent ON issues(parent_id);
-                CREATE INDEX IF NOT EXISTS idx_time_entries_issue ON time_entries(issue_id);
-                "#,
-            )?;
+    /// Rewraps an `open_encrypted` database's data-encryption key under a new
+    /// passphrase-derived KEK, without opening the database or touching a single page of
+    /// `issues.db` — the opposite trade-off from `change_passphrase`. Requires
+    /// `old_passphrase` to unwrap the existing DEK first, so a typo fails loudly rather than
+    /// silently locking the database out from itself.
+    pub fn rotate_passphrase(path: &Path, old_passphrase: &str, new_passphrase: &str) -> Result<()> {
+        let sidecar = Self::key_sidecar_path(path);
+        let (salt, wrapped) = Self::read_key_sidecar(&sidecar)?;
+        let dek = crypto::unwrap_dek(&wrapped, old_passphrase, &salt)?;
+
+        let new_salt = crypto::random_salt();
+        let rewrapped = crypto::wrap_dek(&dek, new_passphrase, &new_salt)?;
+        Self::write_key_sidecar(&sidecar, &new_salt, &rewrapped)
+    }
 
-            // Migration: add parent_id column if upgrading from v1
-            let _ = self.conn.execute(
-                "ALTER TABLE issues ADD COLUMN parent_id INTEGER REFERENCES issues(id) ON DELETE CASCADE",
-                [],
-            );
+    /// Path of the sidecar file `open_encrypted`/`rotate_passphrase` store the wrapped
+    /// data-encryption key in: `<path>.key`, next to the database file itself.
+    fn key_sidecar_path(path: &Path) -> PathBuf {
+        let mut name = path.as_os_str().to_owned();
+        name.push(".key");
+        PathBuf::from(name)
+    }
+
+    /// Whether `path` was created with `open_encrypted` (i.e. has a `.key` sidecar), so callers
+    /// that don't otherwise know whether a store is encrypted — like the CLI's shared
+    /// `get_db` — can decide between `open` and `open_encrypted` before trying either.
+    pub fn is_encrypted(path: &Path) -> bool {
+        Self::key_sidecar_path(path).exists()
+    }
+
+    /// On first open of `path`, generates a fresh DEK and salt and writes the sidecar; on
+    /// later opens, reads the sidecar back and unwraps the stored DEK via `key_manager`.
+    fn load_or_create_dek(path: &Path, key_manager: &dyn KeyManager) -> Result<[u8; crypto::DEK_LEN]> {
+        let sidecar = Self::key_sidecar_path(path);
+        if sidecar.exists() {
+            let (salt, wrapped) = Self::read_key_sidecar(&sidecar)?;
+            key_manager.unwrap_dek(&wrapped, &salt)
+        } else {
+            let dek = crypto::generate_dek();
+            let salt = crypto::random_salt();
+            let wrapped = key_manager.wrap_dek(&dek, &salt)?;
+            Self::write_key_sidecar(&sidecar, &salt, &wrapped)?;
+            Ok(dek)
+        }
+    }
 
-            self.conn
-                .execute(&format!("PRAGMA user_version = {}", SCHEMA_VERSION), [])?;
+    fn read_key_sidecar(sidecar: &Path) -> Result<([u8; crypto::SALT_LEN], Vec<u8>)> {
+        let header = fs::read(sidecar)
+            .with_context(|| format!("Failed to read key sidecar file {}", sidecar.display()))?;
+        if header.len() <= crypto::SALT_LEN {
+            bail!("key sidecar file {} is corrupt (too short)", sidecar.display());
         }
+        let (salt, wrapped) = header.split_at(crypto::SALT_LEN);
+        let salt: [u8; crypto::SALT_LEN] = salt.try_into().expect("split_at guarantees length");
+        Ok((salt, wrapped.to_vec()))
+    }
 
-        // Enable foreign keys
-        self.conn.execute("PRAGMA foreign_keys = ON", [])?;
+    fn write_key_sidecar(sidecar: &Path, salt: &[u8; crypto::SALT_LEN], wrapped: &[u8]) -> Result<()> {
+        let mut header = Vec::with_capacity(crypto::SALT_LEN + wrapped.len());
+        header.extend_from_slice(salt);
+        header.extend_from_slice(wrapped);
+        fs::write(sidecar, header)
+            .with_context(|| format!("Failed to write key sidecar file {}", sidecar.display()))
+    }
 
+    fn init_schema(&self) -> Result<()> {
+        let conn = self.conn()?;
+        migrations::run(&conn).context("Failed to apply schema migrations")?;
         Ok(())
     }
 
+    /// Checks out a pooled connection. Exposed beyond this module so `commands::migrate` and
+    /// `commands::doctor` can drive `migrations::status`/`migrations::migrate_to` and raw
+    /// `PRAGMA` checks without punching dedicated query methods through `Database` for what's
+    /// fundamentally schema-level administration.
+    pub fn conn(&self) -> Result<PooledConnection> {
+        self.pool.get().context("Failed to check out a pooled connection")
+    }
+
     // Issue CRUD
     pub fn create_issue(&self, title: &str, description: Option<&str>, priority: &str) -> Result<i64> {
         self.create_issue_with_parent(title, description, priority, None)
@@ -131,17 +244,19 @@ impl Database {
     }
 
     fn create_issue_with_parent(&self, title: &str, description: Option<&str>, priority: &str, parent_id: Option<i64>) -> Result<i64> {
+        let conn = self.conn()?;
         let now = Utc::now().to_rfc3339();
-        self.conn.execute(
+        conn.execute(
             "INSERT INTO issues (title, description, priority, parent_id, status, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, 'open', ?5, ?5)",
             params![title, description, priority, parent_id, now],
         )?;
-        Ok(self.conn.last_insert_rowid())
+        Ok(conn.last_insert_rowid())
     }
 
     pub fn get_subissues(&self, parent_id: i64) -> Result<Vec<Issue>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, title, description, status, priority, parent_id, created_at, updated_at, closed_at FROM issues WHERE parent_id = ?1 ORDER BY id",
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, title, description, status, priority, parent_id, estimate_seconds, issue_type, epic_id, created_at, updated_at, closed_at FROM issues WHERE parent_id = ?1 ORDER BY id",
         )?;
 
         let issues = stmt
@@ -153,9 +268,12 @@ impl Database {
                     status: row.get(3)?,
                     priority: row.get(4)?,
                     parent_id: row.get(5)?,
-                    created_at: parse_datetime(row.get::<_, String>(6)?),
-                    updated_at: parse_datetime(row.get::<_, String>(7)?),
-                    closed_at: row.get::<_, Option<String>>(8)?.map(parse_datetime),
+                    estimate_seconds: row.get(6)?,
+                    issue_type: row.get(7)?,
+                    epic_id: row.get(8)?,
+                    created_at: parse_datetime(row.get::<_, String>(9)?),
+                    updated_at: parse_datetime(row.get::<_, String>(10)?),
+                    closed_at: row.get::<_, Option<String>>(11)?.map(parse_datetime),
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -164,8 +282,9 @@ impl Database {
     }
 
     pub fn get_issue(&self, id: i64) -> Result<Option<Issue>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, title, description, status, priority, parent_id, created_at, updated_at, closed_at FROM issues WHERE id = ?1",
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, title, description, status, priority, parent_id, estimate_seconds, issue_type, epic_id, created_at, updated_at, closed_at FROM issues WHERE id = ?1",
         )?;
 
         let issue = stmt
@@ -177,9 +296,12 @@ impl Database {
                     status: row.get(3)?,
                     priority: row.get(4)?,
                     parent_id: row.get(5)?,
-                    created_at: parse_datetime(row.get::<_, String>(6)?),
-                    updated_at: parse_datetime(row.get::<_, String>(7)?),
-                    closed_at: row.get::<_, Option<String>>(8)?.map(parse_datetime),
+                    estimate_seconds: row.get(6)?,
+                    issue_type: row.get(7)?,
+                    epic_id: row.get(8)?,
+                    created_at: parse_datetime(row.get::<_, String>(9)?),
+                    updated_at: parse_datetime(row.get::<_, String>(10)?),
+                    closed_at: row.get::<_, Option<String>>(11)?.map(parse_datetime),
                 })
             })
             .ok();
@@ -194,7 +316,7 @@ impl Database {
         priority_filter: Option<&str>,
     ) -> Result<Vec<Issue>> {
         let mut sql = String::from(
-            "SELECT DISTINCT i.id, i.title, i.description, i.status, i.priority, i.parent_id, i.created_at, i.updated_at, i.closed_at FROM issues i",
+            "SELECT DISTINCT i.id, i.title, i.description, i.status, i.priority, i.parent_id, i.estimate_seconds, i.issue_type, i.epic_id, i.created_at, i.updated_at, i.closed_at FROM issues i",
         );
         let mut conditions = Vec::new();
         let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
@@ -227,7 +349,8 @@ impl Database {
 
         sql.push_str(" ORDER BY i.id DESC");
 
-        let mut stmt = self.conn.prepare(&sql)?;
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params_vec.iter().map(|p| p.as_ref()).collect();
 
         let issues = stmt
@@ -239,9 +362,52 @@ impl Database {
                     status: row.get(3)?,
                     priority: row.get(4)?,
                     parent_id: row.get(5)?,
-                    created_at: parse_datetime(row.get::<_, String>(6)?),
-                    updated_at: parse_datetime(row.get::<_, String>(7)?),
-                    closed_at: row.get::<_, Option<String>>(8)?.map(parse_datetime),
+                    estimate_seconds: row.get(6)?,
+                    issue_type: row.get(7)?,
+                    epic_id: row.get(8)?,
+                    created_at: parse_datetime(row.get::<_, String>(9)?),
+                    updated_at: parse_datetime(row.get::<_, String>(10)?),
+                    closed_at: row.get::<_, Option<String>>(11)?.map(parse_datetime),
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(issues)
+    }
+
+    /// Issues whose logged time (the sum of `time_entries.duration_seconds`) exceeds their
+    /// estimate. Issues without an estimate can't be over it, so they're excluded rather than
+    /// treated as unbounded.
+    pub fn list_over_estimate_issues(&self) -> Result<Vec<Issue>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT i.id, i.title, i.description, i.status, i.priority, i.parent_id, i.estimate_seconds,
+                   i.issue_type, i.epic_id, i.created_at, i.updated_at, i.closed_at
+            FROM issues i
+            WHERE i.estimate_seconds IS NOT NULL
+            AND (
+                SELECT COALESCE(SUM(duration_seconds), 0) FROM time_entries WHERE issue_id = i.id
+            ) > i.estimate_seconds
+            ORDER BY i.id
+            "#,
+        )?;
+
+        let issues = stmt
+            .query_map([], |row| {
+                Ok(Issue {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    description: row.get(2)?,
+                    status: row.get(3)?,
+                    priority: row.get(4)?,
+                    parent_id: row.get(5)?,
+                    estimate_seconds: row.get(6)?,
+                    issue_type: row.get(7)?,
+                    epic_id: row.get(8)?,
+                    created_at: parse_datetime(row.get::<_, String>(9)?),
+                    updated_at: parse_datetime(row.get::<_, String>(10)?),
+                    closed_at: row.get::<_, Option<String>>(11)?.map(parse_datetime),
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -255,50 +421,189 @@ impl Database {
         title: Option<&str>,
         description: Option<&str>,
         priority: Option<&str>,
+        estimate_seconds: Option<i64>,
     ) -> Result<bool> {
-        let now = Utc::now().to_rfc3339();
-        let mut updates = vec!["updated_at = ?1".to_string()];
-        let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = vec![Box::new(now)];
+        self.with_transaction(|conn| {
+            update_issue_on(conn, id, title, description, priority, estimate_seconds)
+        })
+    }
 
-        if let Some(t) = title {
-            updates.push(format!("title = ?{}", params_vec.len() + 1));
-            params_vec.push(Box::new(t.to_string()));
-        }
+    /// Sets (or clears) an issue's estimate without journaling a history entry. Used right
+    /// after `create_issue`/`create_subissue`, where there's no prior value to revert to.
+    pub fn set_estimate(&self, id: i64, estimate_seconds: Option<i64>) -> Result<bool> {
+        let rows = self.conn()?.execute(
+            "UPDATE issues SET estimate_seconds = ?1, updated_at = ?2 WHERE id = ?3",
+            params![estimate_seconds, Utc::now().to_rfc3339(), id],
+        )?;
+        Ok(rows > 0)
+    }
 
-        if let Some(d) = description {
-            updates.push(format!("description = ?{}", params_vec.len() + 1));
-            params_vec.push(Box::new(d.to_string()));
-        }
+    /// Sets an issue's type without journaling a history entry. Used right after
+    /// `create_issue`/`create_subissue`, where there's no prior value to revert to.
+    pub fn set_issue_type(&self, id: i64, issue_type: &str) -> Result<bool> {
+        let rows = self.conn()?.execute(
+            "UPDATE issues SET issue_type = ?1, updated_at = ?2 WHERE id = ?3",
+            params![issue_type, Utc::now().to_rfc3339(), id],
+        )?;
+        Ok(rows > 0)
+    }
 
-        if let Some(p) = priority {
-            updates.push(format!("priority = ?{}", params_vec.len() + 1));
-            params_vec.push(Box::new(p.to_string()));
-        }
+    /// Attaches an issue to an epic (or detaches it, when `epic_id` is `None`), independent of
+    /// its `parent_id`. Not journaled, for the same reason `set_estimate` isn't: this is a
+    /// structural link rather than a field whose history is worth reverting to.
+    pub fn attach_to_epic(&self, id: i64, epic_id: Option<i64>) -> Result<bool> {
+        let rows = self.conn()?.execute(
+            "UPDATE issues SET epic_id = ?1, updated_at = ?2 WHERE id = ?3",
+            params![epic_id, Utc::now().to_rfc3339(), id],
+        )?;
+        Ok(rows > 0)
+    }
 
-        params_vec.push(Box::new(id));
-        let sql = format!(
-            "UPDATE issues SET {} WHERE id = ?{}",
-            updates.join(", "),
-            params_vec.len()
-        );
+    /// Issues attached to `epic_id` via `epic_id`, independent of `parent_id`/`get_subissues`.
+    pub fn list_by_epic(&self, epic_id: i64) -> Result<Vec<Issue>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, title, description, status, priority, parent_id, estimate_seconds, issue_type, epic_id, created_at, updated_at, closed_at FROM issues WHERE epic_id = ?1 ORDER BY id",
+        )?;
 
-        let params_refs: Vec<&dyn rusqlite::ToSql> = params_vec.iter().map(|p| p.as_ref()).collect();
-        let rows = self.conn.execute(&sql, params_refs.as_slice())?;
-        Ok(rows > 0)
+        let issues = stmt
+            .query_map([epic_id], |row| {
+                Ok(Issue {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    description: row.get(2)?,
+                    status: row.get(3)?,
+                    priority: row.get(4)?,
+                    parent_id: row.get(5)?,
+                    estimate_seconds: row.get(6)?,
+                    issue_type: row.get(7)?,
+                    epic_id: row.get(8)?,
+                    created_at: parse_datetime(row.get::<_, String>(9)?),
+                    updated_at: parse_datetime(row.get::<_, String>(10)?),
+                    closed_at: row.get::<_, Option<String>>(11)?.map(parse_datetime),
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(issues)
     }
 
-    pub fn close_issue(&self, id: i64) -> Result<bool> {
-        let now = Utc::now().to_rfc3339();
-        let rows = self.conn.execute(
-            "UPDATE issues SET status = 'closed', closed_at = ?1, updated_at = ?1 WHERE id = ?2",
-            params![now, id],
+    // Change journal
+    pub fn get_issue_history(&self, issue_id: i64) -> Result<Vec<HistoryEntry>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, issue_id, field, old_value, new_value, changed_at, reverted \
+             FROM issue_history WHERE issue_id = ?1 ORDER BY changed_at DESC, id DESC",
         )?;
-        Ok(rows > 0)
+        let entries = stmt
+            .query_map([issue_id], |row| {
+                Ok(HistoryEntry {
+                    id: row.get(0)?,
+                    issue_id: row.get(1)?,
+                    field: row.get(2)?,
+                    old_value: row.get(3)?,
+                    new_value: row.get(4)?,
+                    changed_at: parse_datetime(row.get::<_, String>(5)?),
+                    reverted: row.get::<_, i64>(6)? != 0,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(entries)
+    }
+
+    /// Reverts the most recent not-yet-reverted change to `issue_id`, restoring every field
+    /// journaled in that update (they share a `changed_at`), and marks those rows reverted so
+    /// a second `undo` moves on to the change before it.
+    pub fn undo_last_change(&self, issue_id: i64) -> Result<Vec<String>> {
+        self.with_transaction(|conn| {
+            let last_changed_at: Option<String> = conn
+                .query_row(
+                    "SELECT changed_at FROM issue_history WHERE issue_id = ?1 AND reverted = 0 \
+                     ORDER BY changed_at DESC, id DESC LIMIT 1",
+                    [issue_id],
+                    |row| row.get(0),
+                )
+                .optional()?;
+
+            let Some(changed_at) = last_changed_at else {
+                return Ok(Vec::new());
+            };
+
+            let mut stmt = conn.prepare(
+                "SELECT id, field, old_value FROM issue_history \
+                 WHERE issue_id = ?1 AND changed_at = ?2 AND reverted = 0",
+            )?;
+            let rows = stmt
+                .query_map(params![issue_id, changed_at], |row| {
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, Option<String>>(2)?,
+                    ))
+                })?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            drop(stmt);
+
+            let mut reverted_fields = Vec::new();
+            for (history_id, field, old_value) in rows {
+                let column = match field.as_str() {
+                    "title" | "description" | "priority" | "estimate_seconds" => field.as_str(),
+                    other => bail!("Unrecognized journaled field '{}'", other),
+                };
+                conn.execute(
+                    &format!(
+                        "UPDATE issues SET {} = ?1, updated_at = ?2 WHERE id = ?3",
+                        column
+                    ),
+                    params![old_value, Utc::now().to_rfc3339(), issue_id],
+                )?;
+                conn.execute(
+                    "UPDATE issue_history SET reverted = 1 WHERE id = ?1",
+                    [history_id],
+                )?;
+                reverted_fields.push(field);
+            }
+
+            Ok(reverted_fields)
+        })
+    }
+
+    /// Keeps only the `keep` most recent journal rows for `issue_id`, discarding older ones so
+    /// the history table doesn't grow without bound.
+    pub fn prune_issue_history(&self, issue_id: i64, keep: i64) -> Result<usize> {
+        let rows = self.conn()?.execute(
+            "DELETE FROM issue_history WHERE issue_id = ?1 AND id NOT IN ( \
+                SELECT id FROM issue_history WHERE issue_id = ?1 \
+                ORDER BY changed_at DESC, id DESC LIMIT ?2 \
+             )",
+            params![issue_id, keep],
+        )?;
+        Ok(rows)
+    }
+
+    /// Checks out a pooled connection and runs `f` inside an explicit SQLite transaction on
+    /// it: `f`'s writes are committed only if it returns `Ok`, and are rolled back (by
+    /// `Transaction`'s drop handler) if it returns `Err`. Used for multi-row operations that
+    /// must be all-or-nothing, e.g. `commands::update` applying the same edit to several
+    /// issues at once.
+    pub fn with_transaction<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&Connection) -> Result<T>,
+    {
+        let conn = self.conn()?;
+        let tx = conn.unchecked_transaction()?;
+        let result = f(&tx)?;
+        tx.commit()?;
+        Ok(result)
+    }
+
+    pub fn close_issue(&self, id: i64) -> Result<bool> {
+        close_issue_on(&self.conn()?, id)
     }
 
     pub fn reopen_issue(&self, id: i64) -> Result<bool> {
         let now = Utc::now().to_rfc3339();
-        let rows = self.conn.execute(
+        let rows = self.conn()?.execute(
             "UPDATE issues SET status = 'open', closed_at = NULL, updated_at = ?1 WHERE id = ?2",
             params![now, id],
         )?;
@@ -306,31 +611,21 @@ impl Database {
     }
 
     pub fn delete_issue(&self, id: i64) -> Result<bool> {
-        let rows = self.conn.execute("DELETE FROM issues WHERE id = ?1", [id])?;
-        Ok(rows > 0)
+        delete_issue_on(&self.conn()?, id)
     }
 
     // Labels
     pub fn add_label(&self, issue_id: i64, label: &str) -> Result<bool> {
-        let result = self.conn.execute(
-            "INSERT OR IGNORE INTO labels (issue_id, label) VALUES (?1, ?2)",
-            params![issue_id, label],
-        )?;
-        Ok(result > 0)
+        add_label_on(&self.conn()?, issue_id, label)
     }
 
     pub fn remove_label(&self, issue_id: i64, label: &str) -> Result<bool> {
-        let rows = self.conn.execute(
-            "DELETE FROM labels WHERE issue_id = ?1 AND label = ?2",
-            params![issue_id, label],
-        )?;
-        Ok(rows > 0)
+        remove_label_on(&self.conn()?, issue_id, label)
     }
 
     pub fn get_labels(&self, issue_id: i64) -> Result<Vec<String>> {
-        let mut stmt = self
-            .conn
-            .prepare("SELECT label FROM labels WHERE issue_id = ?1 ORDER BY label")?;
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare("SELECT label FROM labels WHERE issue_id = ?1 ORDER BY label")?;
         let labels = stmt
             .query_map([issue_id], |row| row.get(0))?
             .collect::<std::result::Result<Vec<String>, _>>()?;
@@ -339,16 +634,18 @@ impl Database {
 
     // Comments
     pub fn add_comment(&self, issue_id: i64, content: &str) -> Result<i64> {
+        let conn = self.conn()?;
         let now = Utc::now().to_rfc3339();
-        self.conn.execute(
+        conn.execute(
             "INSERT INTO comments (issue_id, content, created_at) VALUES (?1, ?2, ?3)",
             params![issue_id, content, now],
         )?;
-        Ok(self.conn.last_insert_rowid())
+        Ok(conn.last_insert_rowid())
     }
 
     pub fn get_comments(&self, issue_id: i64) -> Result<Vec<Comment>> {
-        let mut stmt = self.conn.prepare(
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
             "SELECT id, issue_id, content, created_at FROM comments WHERE issue_id = ?1 ORDER BY created_at",
         )?;
         let comments = stmt
@@ -365,16 +662,15 @@ impl Database {
     }
 
     // Dependencies
+    /// Rejects the insert with an error if `blocker_id` is already reachable from `blocked_id`
+    /// by following existing `blocks` edges — adding blocker_id -> blocked_id on top of that
+    /// would close a cycle.
     pub fn add_dependency(&self, blocked_id: i64, blocker_id: i64) -> Result<bool> {
-        let result = self.conn.execute(
-            "INSERT OR IGNORE INTO dependencies (blocker_id, blocked_id) VALUES (?1, ?2)",
-            params![blocker_id, blocked_id],
-        )?;
-        Ok(result > 0)
+        add_dependency_on(&self.conn()?, blocked_id, blocker_id)
     }
 
     pub fn remove_dependency(&self, blocked_id: i64, blocker_id: i64) -> Result<bool> {
-        let rows = self.conn.execute(
+        let rows = self.conn()?.execute(
             "DELETE FROM dependencies WHERE blocker_id = ?1 AND blocked_id = ?2",
             params![blocker_id, blocked_id],
         )?;
@@ -382,9 +678,8 @@ impl Database {
     }
 
     pub fn get_blockers(&self, issue_id: i64) -> Result<Vec<i64>> {
-        let mut stmt = self
-            .conn
-            .prepare("SELECT blocker_id FROM dependencies WHERE blocked_id = ?1")?;
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare("SELECT blocker_id FROM dependencies WHERE blocked_id = ?1")?;
         let blockers = stmt
             .query_map([issue_id], |row| row.get(0))?
             .collect::<std::result::Result<Vec<i64>, _>>()?;
@@ -392,9 +687,8 @@ impl Database {
     }
 
     pub fn get_blocking(&self, issue_id: i64) -> Result<Vec<i64>> {
-        let mut stmt = self
-            .conn
-            .prepare("SELECT blocked_id FROM dependencies WHERE blocker_id = ?1")?;
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare("SELECT blocked_id FROM dependencies WHERE blocker_id = ?1")?;
         let blocking = stmt
             .query_map([issue_id], |row| row.get(0))?
             .collect::<std::result::Result<Vec<i64>, _>>()?;
@@ -402,9 +696,11 @@ impl Database {
     }
 
     pub fn list_blocked_issues(&self) -> Result<Vec<Issue>> {
-        let mut stmt = self.conn.prepare(
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
             r#"
-            SELECT DISTINCT i.id, i.title, i.description, i.status, i.priority, i.parent_id, i.created_at, i.updated_at, i.closed_at
+            SELECT DISTINCT i.id, i.title, i.description, i.status, i.priority, i.parent_id, i.estimate_seconds,
+                   i.issue_type, i.epic_id, i.created_at, i.updated_at, i.closed_at
             FROM issues i
             JOIN dependencies d ON i.id = d.blocked_id
             JOIN issues blocker ON d.blocker_id = blocker.id
@@ -422,9 +718,12 @@ impl Database {
                     status: row.get(3)?,
                     priority: row.get(4)?,
                     parent_id: row.get(5)?,
-                    created_at: parse_datetime(row.get::<_, String>(6)?),
-                    updated_at: parse_datetime(row.get::<_, String>(7)?),
-                    closed_at: row.get::<_, Option<String>>(8)?.map(parse_datetime),
+                    estimate_seconds: row.get(6)?,
+                    issue_type: row.get(7)?,
+                    epic_id: row.get(8)?,
+                    created_at: parse_datetime(row.get::<_, String>(9)?),
+                    updated_at: parse_datetime(row.get::<_, String>(10)?),
+                    closed_at: row.get::<_, Option<String>>(11)?.map(parse_datetime),
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -433,9 +732,11 @@ impl Database {
     }
 
     pub fn list_ready_issues(&self) -> Result<Vec<Issue>> {
-        let mut stmt = self.conn.prepare(
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
             r#"
-            SELECT i.id, i.title, i.description, i.status, i.priority, i.parent_id, i.created_at, i.updated_at, i.closed_at
+            SELECT i.id, i.title, i.description, i.status, i.priority, i.parent_id, i.estimate_seconds,
+                   i.issue_type, i.epic_id, i.created_at, i.updated_at, i.closed_at
             FROM issues i
             WHERE i.status = 'open'
             AND NOT EXISTS (
@@ -456,9 +757,12 @@ impl Database {
                     status: row.get(3)?,
                     priority: row.get(4)?,
                     parent_id: row.get(5)?,
-                    created_at: parse_datetime(row.get::<_, String>(6)?),
-                    updated_at: parse_datetime(row.get::<_, String>(7)?),
-                    closed_at: row.get::<_, Option<String>>(8)?.map(parse_datetime),
+                    estimate_seconds: row.get(6)?,
+                    issue_type: row.get(7)?,
+                    epic_id: row.get(8)?,
+                    created_at: parse_datetime(row.get::<_, String>(9)?),
+                    updated_at: parse_datetime(row.get::<_, String>(10)?),
+                    closed_at: row.get::<_, Option<String>>(11)?.map(parse_datetime),
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -466,19 +770,84 @@ impl Database {
         Ok(issues)
     }
 
+    /// Orders every open issue so that every issue appears after all of its (open) blockers,
+    /// breaking ties by priority then id. Builds the blocker/blocked adjacency for open issues
+    /// in memory and runs Kahn's algorithm: a ready set starts with every zero-in-degree issue,
+    /// and each pop decrements the in-degree of what it blocks, feeding newly-freed issues back
+    /// into the ready set. If the ready set empties before every issue is emitted, the
+    /// remainder forms a dependency cycle, which `add_dependency` should have prevented but
+    /// this still reports defensively rather than silently dropping those issues.
+    pub fn list_scheduled_issues(&self) -> Result<Vec<Issue>> {
+        let open_issues = self.list_issues(Some("open"), None, None)?;
+        let open_ids: HashSet<i64> = open_issues.iter().map(|i| i.id).collect();
+        let issues_by_id: HashMap<i64, Issue> = open_issues.into_iter().map(|i| (i.id, i)).collect();
+
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare("SELECT blocker_id, blocked_id FROM dependencies")?;
+        let edges: Vec<(i64, i64)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        drop(stmt);
+
+        let mut blocks: HashMap<i64, Vec<i64>> = HashMap::new();
+        let mut in_degree: HashMap<i64, i32> = issues_by_id.keys().map(|&id| (id, 0)).collect();
+        for (blocker_id, blocked_id) in edges {
+            if open_ids.contains(&blocker_id) && open_ids.contains(&blocked_id) {
+                blocks.entry(blocker_id).or_default().push(blocked_id);
+                *in_degree.entry(blocked_id).or_insert(0) += 1;
+            }
+        }
+
+        let mut ready: Vec<i64> = in_degree
+            .iter()
+            .filter(|(_, &degree)| degree == 0)
+            .map(|(&id, _)| id)
+            .collect();
+
+        let mut ordered = Vec::new();
+        while !ready.is_empty() {
+            ready.sort_by_key(|id| (priority_rank(&issues_by_id[id].priority), *id));
+            let next = ready.remove(0);
+            ordered.push(issues_by_id[&next].clone());
+
+            if let Some(blocked) = blocks.get(&next) {
+                for &blocked_id in blocked {
+                    let degree = in_degree.get_mut(&blocked_id).expect("seeded above");
+                    *degree -= 1;
+                    if *degree == 0 {
+                        ready.push(blocked_id);
+                    }
+                }
+            }
+        }
+
+        if ordered.len() != issues_by_id.len() {
+            let scheduled: HashSet<i64> = ordered.iter().map(|i| i.id).collect();
+            let stuck: Vec<String> = issues_by_id
+                .keys()
+                .filter(|id| !scheduled.contains(id))
+                .map(|id| format!("#{}", id))
+                .collect();
+            bail!("Dependency cycle detected among open issues: {}", stuck.join(", "));
+        }
+
+        Ok(ordered)
+    }
+
     // Sessions
     pub fn start_session(&self) -> Result<i64> {
+        let conn = self.conn()?;
         let now = Utc::now().to_rfc3339();
-        self.conn.execute(
+        conn.execute(
             "INSERT INTO sessions (started_at) VALUES (?1)",
             params![now],
         )?;
-        Ok(self.conn.last_insert_rowid())
+        Ok(conn.last_insert_rowid())
     }
 
     pub fn end_session(&self, id: i64, notes: Option<&str>) -> Result<bool> {
         let now = Utc::now().to_rfc3339();
-        let rows = self.conn.execute(
+        let rows = self.conn()?.execute(
             "UPDATE sessions SET ended_at = ?1, handoff_notes = ?2 WHERE id = ?3",
             params![now, notes, id],
         )?;
@@ -486,7 +855,8 @@ impl Database {
     }
 
     pub fn get_current_session(&self) -> Result<Option<Session>> {
-        let mut stmt = self.conn.prepare(
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
             "SELECT id, started_at, ended_at, active_issue_id, handoff_notes FROM sessions WHERE ended_at IS NULL ORDER BY id DESC LIMIT 1",
         )?;
 
@@ -506,7 +876,8 @@ impl Database {
     }
 
     pub fn get_last_session(&self) -> Result<Option<Session>> {
-        let mut stmt = self.conn.prepare(
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
             "SELECT id, started_at, ended_at, active_issue_id, handoff_notes FROM sessions WHERE ended_at IS NOT NULL ORDER BY id DESC LIMIT 1",
         )?;
 
@@ -525,8 +896,31 @@ impl Database {
         Ok(session)
     }
 
+    /// Every session, oldest first. Used by `export` to carry session/handoff history in the
+    /// portable JSON snapshot.
+    pub fn list_sessions(&self) -> Result<Vec<Session>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, started_at, ended_at, active_issue_id, handoff_notes FROM sessions ORDER BY id",
+        )?;
+
+        let sessions = stmt
+            .query_map([], |row| {
+                Ok(Session {
+                    id: row.get(0)?,
+                    started_at: parse_datetime(row.get::<_, String>(1)?),
+                    ended_at: row.get::<_, Option<String>>(2)?.map(parse_datetime),
+                    active_issue_id: row.get(3)?,
+                    handoff_notes: row.get(4)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(sessions)
+    }
+
     pub fn set_session_issue(&self, session_id: i64, issue_id: i64) -> Result<bool> {
-        let rows = self.conn.execute(
+        let rows = self.conn()?.execute(
             "UPDATE sessions SET active_issue_id = ?1 WHERE id = ?2",
             params![issue_id, session_id],
         )?;
@@ -535,21 +929,22 @@ impl Database {
 
     // Time tracking
     pub fn start_timer(&self, issue_id: i64) -> Result<i64> {
+        let conn = self.conn()?;
         let now = Utc::now().to_rfc3339();
-        self.conn.execute(
+        conn.execute(
             "INSERT INTO time_entries (issue_id, started_at) VALUES (?1, ?2)",
             params![issue_id, now],
         )?;
-        Ok(self.conn.last_insert_rowid())
+        Ok(conn.last_insert_rowid())
     }
 
     pub fn stop_timer(&self, issue_id: i64) -> Result<bool> {
+        let conn = self.conn()?;
         let now = Utc::now();
         let now_str = now.to_rfc3339();
 
         // Get the active entry
-        let started_at: Option<String> = self
-            .conn
+        let started_at: Option<String> = conn
             .query_row(
                 "SELECT started_at FROM time_entries WHERE issue_id = ?1 AND ended_at IS NULL",
                 [issue_id],
@@ -563,7 +958,7 @@ impl Database {
                 .unwrap_or(now);
             let duration = now.signed_duration_since(start_dt).num_seconds();
 
-            let rows = self.conn.execute(
+            let rows = conn.execute(
                 "UPDATE time_entries SET ended_at = ?1, duration_seconds = ?2 WHERE issue_id = ?3 AND ended_at IS NULL",
                 params![now_str, duration, issue_id],
             )?;
@@ -575,7 +970,7 @@ impl Database {
 
     pub fn get_active_timer(&self) -> Result<Option<(i64, DateTime<Utc>)>> {
         let result: Option<(i64, String)> = self
-            .conn
+            .conn()?
             .query_row(
                 "SELECT issue_id, started_at FROM time_entries WHERE ended_at IS NULL ORDER BY id DESC LIMIT 1",
                 [],
@@ -586,9 +981,286 @@ impl Database {
         Ok(result.map(|(id, started)| (id, parse_datetime(started))))
     }
 
+    /// Like `get_active_timer`, but also returns the point it was last confirmed alive: its
+    /// last-recorded heartbeat (see `record_heartbeat`), or `started_at` if none was ever
+    /// recorded. Used to judge whether an active timer is still live or was orphaned by a
+    /// crash.
+    pub fn get_active_timer_last_seen(&self) -> Result<Option<(i64, DateTime<Utc>)>> {
+        let result: Option<(i64, String, Option<String>)> = self
+            .conn()?
+            .query_row(
+                "SELECT issue_id, started_at, heartbeat_at FROM time_entries \
+                 WHERE ended_at IS NULL ORDER BY id DESC LIMIT 1",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .ok();
+
+        Ok(result.map(|(id, started, heartbeat)| {
+            (id, heartbeat.map(|h| parse_datetime(h)).unwrap_or_else(|| parse_datetime(started)))
+        }))
+    }
+
+    /// Updates the active timer's last-seen heartbeat, so a later crash-recovery check
+    /// (`close_stale_timer`) has something better than `started_at` to close the session at.
+    /// A no-op if `issue_id` has no active timer.
+    pub fn record_heartbeat(&self, issue_id: i64) -> Result<()> {
+        self.conn()?.execute(
+            "UPDATE time_entries SET heartbeat_at = ?1 WHERE issue_id = ?2 AND ended_at IS NULL",
+            params![Utc::now().to_rfc3339(), issue_id],
+        )?;
+        Ok(())
+    }
+
+    /// Finalizes an orphaned active timer for `issue_id` (the process was killed or the machine
+    /// rebooted before `stop` ran): ends the session at its last recorded heartbeat, or at
+    /// `started_at` if none was ever recorded, rather than "now" which would count the downtime
+    /// itself as tracked work. Returns the recorded duration, or `None` if there was no active
+    /// timer to close.
+    pub fn close_stale_timer(&self, issue_id: i64) -> Result<Option<i64>> {
+        let conn = self.conn()?;
+
+        let row: Option<(String, Option<String>)> = conn
+            .query_row(
+                "SELECT started_at, heartbeat_at FROM time_entries WHERE issue_id = ?1 AND ended_at IS NULL",
+                [issue_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        let Some((started_at, heartbeat_at)) = row else {
+            return Ok(None);
+        };
+
+        let started_at = parse_datetime(started_at);
+        let ended_at = heartbeat_at.map(parse_datetime).unwrap_or(started_at);
+        let duration = ended_at.signed_duration_since(started_at).num_seconds().max(0);
+
+        conn.execute(
+            "UPDATE time_entries SET ended_at = ?1, duration_seconds = ?2 WHERE issue_id = ?3 AND ended_at IS NULL",
+            params![ended_at.to_rfc3339(), duration, issue_id],
+        )?;
+        Ok(Some(duration))
+    }
+
+    /// Records `duration_seconds` of already-completed work on `issue_id`, for time logged after
+    /// the fact rather than tracked live with `start`/`stop`. Stored as a regular, already-ended
+    /// `time_entries` row (`started_at` backdated by the duration) so it's counted by
+    /// `get_total_time` exactly like a timer session.
+    pub fn log_time(&self, issue_id: i64, duration_seconds: i64) -> Result<i64> {
+        let conn = self.conn()?;
+        let now = Utc::now();
+        let started_at = now - chrono::Duration::seconds(duration_seconds);
+        conn.execute(
+            "INSERT INTO time_entries (issue_id, started_at, ended_at, duration_seconds) VALUES (?1, ?2, ?3, ?4)",
+            params![issue_id, started_at.to_rfc3339(), now.to_rfc3339(), duration_seconds],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Full-text search over issue titles/descriptions and comment bodies. Matches from either
+    /// source are unioned and collapsed per-issue (`MIN(score)`, since lower `bm25()` values are
+    /// more relevant), so an issue found only through a comment still surfaces, just typically
+    /// ranked behind a direct title/description hit.
+    ///
+    /// `query` is first expanded by `expand_query_terms`, which widens each token into an OR
+    /// group covering the exact token, any indexed term within bounded edit distance of it (a
+    /// MeiliSearch-style typo tolerance: distance 1 for tokens of 4+ chars, distance 2 for 8+),
+    /// and — for the final token only — a prefix match, so a still-being-typed last word still
+    /// hits. The expansion is itself quoted per FTS5's rules, so arbitrary user input can't
+    /// produce a MATCH syntax error. Results are paginated with `limit`/`offset` applied after
+    /// ranking, so callers can page through large result sets without re-running the match.
+    pub fn search_issues(
+        &self,
+        query: &str,
+        status_filter: Option<&str>,
+        priority_filter: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<SearchHit>> {
+        let conn = self.conn()?;
+        let fts_query = expand_query_terms(&conn, query)?;
+        if fts_query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut sql = String::from(
+            "SELECT id, title, description, status, priority, parent_id, estimate_seconds, issue_type, epic_id, created_at, updated_at, closed_at, \
+             MIN(score) AS score, MAX(snippet) AS snippet \
+             FROM ( \
+                 SELECT i.id, i.title, i.description, i.status, i.priority, i.parent_id, i.estimate_seconds, \
+                        i.issue_type, i.epic_id, i.created_at, i.updated_at, i.closed_at, \
+                        bm25(issues_fts) AS score, \
+                        snippet(issues_fts, -1, '[', ']', '...', 10) AS snippet \
+                 FROM issues_fts \
+                 JOIN issues i ON i.id = issues_fts.rowid \
+                 WHERE issues_fts MATCH ?1 \
+                 UNION ALL \
+                 SELECT i.id, i.title, i.description, i.status, i.priority, i.parent_id, i.estimate_seconds, \
+                        i.issue_type, i.epic_id, i.created_at, i.updated_at, i.closed_at, \
+                        0.0 AS score, NULL AS snippet \
+                 FROM issues i \
+                 WHERE i.id IN ( \
+                     SELECT c.issue_id FROM comments c \
+                     JOIN comments_fts ON comments_fts.rowid = c.id \
+                     WHERE comments_fts MATCH ?2 \
+                 ) \
+             ) combined",
+        );
+
+        let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> =
+            vec![Box::new(fts_query.clone()), Box::new(fts_query)];
+
+        let mut conditions = Vec::new();
+        if let Some(status) = status_filter {
+            params_vec.push(Box::new(status.to_string()));
+            conditions.push(format!("status = ?{}", params_vec.len()));
+        }
+
+        if let Some(priority) = priority_filter {
+            params_vec.push(Box::new(priority.to_string()));
+            conditions.push(format!("priority = ?{}", params_vec.len()));
+        }
+
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+
+        sql.push_str(" GROUP BY id ORDER BY score");
+
+        params_vec.push(Box::new(limit));
+        sql.push_str(&format!(" LIMIT ?{}", params_vec.len()));
+        params_vec.push(Box::new(offset));
+        sql.push_str(&format!(" OFFSET ?{}", params_vec.len()));
+
+        let mut stmt = conn.prepare(&sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params_vec.iter().map(|p| p.as_ref()).collect();
+
+        let results = stmt
+            .query_map(params_refs.as_slice(), |row| {
+                let issue = Issue {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    description: row.get(2)?,
+                    status: row.get(3)?,
+                    priority: row.get(4)?,
+                    parent_id: row.get(5)?,
+                    estimate_seconds: row.get(6)?,
+                    issue_type: row.get(7)?,
+                    epic_id: row.get(8)?,
+                    created_at: parse_datetime(row.get::<_, String>(9)?),
+                    updated_at: parse_datetime(row.get::<_, String>(10)?),
+                    closed_at: row.get::<_, Option<String>>(11)?.map(parse_datetime),
+                };
+                let score = row.get(12)?;
+                let snippet = row
+                    .get::<_, Option<String>>(13)?
+                    .unwrap_or_else(|| truncate_snippet(&issue.title, 80));
+                Ok(SearchHit {
+                    issue,
+                    score,
+                    snippet,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(results)
+    }
+
+    /// Runs `PRAGMA integrity_check` and `PRAGMA foreign_key_check`, cross-references the
+    /// applied schema version against `migrations::MIGRATIONS`, and scans `issues` for values
+    /// that violate invariants this crate assumes elsewhere (a known `priority`, a known
+    /// `status`) but doesn't enforce with a SQL `CHECK` constraint. Doesn't modify anything;
+    /// see `repair` for the `--fix` path.
+    pub fn health_check(&self) -> Result<HealthReport> {
+        let conn = self.conn()?;
+
+        let integrity_errors: Vec<String> = conn
+            .prepare("PRAGMA integrity_check")?
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|line| line != "ok")
+            .collect();
+
+        let foreign_key_errors: Vec<String> = conn
+            .prepare("PRAGMA foreign_key_check")?
+            .query_map([], |row| {
+                let table: String = row.get(0)?;
+                let rowid: Option<i64> = row.get(1)?;
+                let parent: String = row.get(2)?;
+                Ok(format!(
+                    "row {:?} in '{}' references missing '{}'",
+                    rowid, table, parent
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let statuses = migrations::status(&conn)?;
+        let schema_version = statuses.iter().filter(|s| s.applied).count() as i32;
+        let schema_total = statuses.len() as i32;
+
+        let mut problems = Vec::new();
+        for (id, priority, status) in scan_issue_invariants(&conn)? {
+            if !VALID_PRIORITIES.contains(&priority.as_str()) {
+                problems.push(HealthIssue {
+                    issue_id: Some(id),
+                    problem: format!("invalid priority '{}'", priority),
+                });
+            }
+            if !VALID_STATUSES.contains(&status.as_str()) {
+                problems.push(HealthIssue {
+                    issue_id: Some(id),
+                    problem: format!("invalid status '{}'", status),
+                });
+            }
+        }
+
+        Ok(HealthReport {
+            integrity_errors,
+            foreign_key_errors,
+            schema_version,
+            schema_total,
+            problems,
+        })
+    }
+
+    /// Normalizes out-of-range priorities to `DEFAULT_PRIORITY` inside a transaction and
+    /// reports every row it touched. An invalid `status` has no safe default (closing or
+    /// reopening an issue is a meaningful user action), so those rows are only reported, not
+    /// changed. Returns a human-readable line per row it fixed or flagged as unfixable.
+    pub fn repair(&self) -> Result<Vec<String>> {
+        self.with_transaction(|conn| {
+            let rows = scan_issue_invariants(conn)?;
+
+            let mut report = Vec::new();
+            for (id, priority, status) in rows {
+                if !VALID_PRIORITIES.contains(&priority.as_str()) {
+                    conn.execute(
+                        "UPDATE issues SET priority = ?1 WHERE id = ?2",
+                        params![DEFAULT_PRIORITY, id],
+                    )?;
+                    report.push(format!(
+                        "issue #{}: priority '{}' -> '{}'",
+                        id, priority, DEFAULT_PRIORITY
+                    ));
+                }
+                if !VALID_STATUSES.contains(&status.as_str()) {
+                    report.push(format!(
+                        "issue #{}: status '{}' cannot be safely repaired, left as-is",
+                        id, status
+                    ));
+                }
+            }
+
+            Ok(report)
+        })
+    }
+
     pub fn get_total_time(&self, issue_id: i64) -> Result<i64> {
         let total: i64 = self
-            .conn
+            .conn()?
             .query_row(
                 "SELECT COALESCE(SUM(duration_seconds), 0) FROM time_entries WHERE issue_id = ?1 AND duration_seconds IS NOT NULL",
                 [issue_id],
@@ -597,10 +1269,983 @@ impl Database {
             .unwrap_or(0);
         Ok(total)
     }
+
+    /// Total tracked time across every issue, for the daemon's `/metrics` endpoint — the
+    /// whole-database equivalent of `get_total_time`.
+    pub fn get_total_tracked_time(&self) -> Result<i64> {
+        let total: i64 = self
+            .conn()?
+            .query_row(
+                "SELECT COALESCE(SUM(duration_seconds), 0) FROM time_entries WHERE duration_seconds IS NOT NULL",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap_or(0);
+        Ok(total)
+    }
+
+    /// Individual `time_entries` rows for `chainlink sessions`: every session for `issue_id` if
+    /// given (oldest first, so the history reads top-to-bottom like a log), or the `limit` most
+    /// recent sessions across all issues otherwise.
+    pub fn list_time_entries(&self, issue_id: Option<i64>, limit: i64) -> Result<Vec<TimeEntry>> {
+        let conn = self.conn()?;
+
+        let map_row = |row: &rusqlite::Row| {
+            let started_at: String = row.get(2)?;
+            let ended_at: Option<String> = row.get(3)?;
+            Ok(TimeEntry {
+                id: row.get(0)?,
+                issue_id: row.get(1)?,
+                started_at: parse_datetime(started_at),
+                ended_at: ended_at.map(parse_datetime),
+                duration_seconds: row.get(4)?,
+            })
+        };
+
+        let entries = match issue_id {
+            Some(issue_id) => {
+                let mut stmt = conn.prepare(
+                    "SELECT id, issue_id, started_at, ended_at, duration_seconds FROM time_entries \
+                     WHERE issue_id = ?1 ORDER BY started_at ASC",
+                )?;
+                stmt.query_map([issue_id], map_row)?.collect::<std::result::Result<Vec<_>, _>>()?
+            }
+            None => {
+                let mut stmt = conn.prepare(
+                    "SELECT id, issue_id, started_at, ended_at, duration_seconds FROM time_entries \
+                     ORDER BY started_at DESC LIMIT ?1",
+                )?;
+                stmt.query_map([limit], map_row)?.collect::<std::result::Result<Vec<_>, _>>()?
+            }
+        };
+
+        Ok(entries)
+    }
+
+    /// Serializes every issue, label, dependency, comment, session, and time entry into a
+    /// single JSON snapshot, then encrypts it (see `crypto::encrypt`) under a key derived from
+    /// `passphrase` and writes the resulting blob to `path`. The backup is portable: restoring
+    /// it with `import_encrypted` on another machine reproduces the database byte-for-byte at
+    /// the row level, independent of whichever passphrase (if any) the source database itself
+    /// was opened with.
+    pub fn export_encrypted(&self, path: &Path, passphrase: &str) -> Result<()> {
+        let conn = self.conn()?;
+        let backup = BackupData {
+            version: 1,
+            issues: self.list_issues(Some("all"), None, None)?,
+            labels: query_all(
+                &conn,
+                "SELECT issue_id, label FROM labels",
+                |row| Ok(BackupLabel { issue_id: row.get(0)?, label: row.get(1)? }),
+            )?,
+            dependencies: query_all(
+                &conn,
+                "SELECT blocker_id, blocked_id FROM dependencies",
+                |row| Ok(BackupDependency { blocker_id: row.get(0)?, blocked_id: row.get(1)? }),
+            )?,
+            comments: query_all(&conn, "SELECT id, issue_id, content, created_at FROM comments", |row| {
+                Ok(Comment {
+                    id: row.get(0)?,
+                    issue_id: row.get(1)?,
+                    content: row.get(2)?,
+                    created_at: parse_datetime(row.get(3)?),
+                })
+            })?,
+            sessions: query_all(
+                &conn,
+                "SELECT id, started_at, ended_at, active_issue_id, handoff_notes FROM sessions",
+                |row| {
+                    let ended_at: Option<String> = row.get(2)?;
+                    Ok(Session {
+                        id: row.get(0)?,
+                        started_at: parse_datetime(row.get(1)?),
+                        ended_at: ended_at.map(parse_datetime),
+                        active_issue_id: row.get(3)?,
+                        handoff_notes: row.get(4)?,
+                    })
+                },
+            )?,
+            time_entries: query_all(
+                &conn,
+                "SELECT id, issue_id, started_at, ended_at, duration_seconds FROM time_entries",
+                |row| {
+                    let ended_at: Option<String> = row.get(3)?;
+                    Ok(TimeEntry {
+                        id: row.get(0)?,
+                        issue_id: row.get(1)?,
+                        started_at: parse_datetime(row.get(2)?),
+                        ended_at: ended_at.map(parse_datetime),
+                        duration_seconds: row.get(4)?,
+                    })
+                },
+            )?,
+        };
+        drop(conn);
+
+        let plaintext = serde_json::to_vec(&backup).context("Failed to serialize backup")?;
+        let blob = crypto::encrypt(&plaintext, passphrase)?;
+        fs::write(path, blob).context("Failed to write encrypted backup")?;
+        Ok(())
+    }
+
+    /// Reverses `export_encrypted`: decrypts `path` under `passphrase`, parses the JSON
+    /// snapshot, and replaces every issue, label, dependency, comment, session, and time entry
+    /// in this database with the snapshot's rows (preserving original ids), inside a single
+    /// transaction so a malformed backup leaves the database untouched.
+    pub fn import_encrypted(&self, path: &Path, passphrase: &str) -> Result<()> {
+        let ciphertext = fs::read(path).context("Failed to read encrypted backup")?;
+        let plaintext = crypto::decrypt(&ciphertext, passphrase)?;
+        let backup: BackupData =
+            serde_json::from_slice(&plaintext).context("Backup did not contain a valid chainlink snapshot")?;
+
+        self.with_transaction(|tx| {
+            for table in [
+                "time_entries",
+                "sessions",
+                "comments",
+                "dependencies",
+                "labels",
+                "issues",
+            ] {
+                tx.execute(&format!("DELETE FROM {}", table), [])?;
+            }
+
+            for issue in &backup.issues {
+                tx.execute(
+                    "INSERT INTO issues (id, title, description, status, priority, parent_id, estimate_seconds, issue_type, epic_id, created_at, updated_at, closed_at) \
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                    params![
+                        issue.id,
+                        issue.title,
+                        issue.description,
+                        issue.status,
+                        issue.priority,
+                        issue.parent_id,
+                        issue.estimate_seconds,
+                        issue.issue_type,
+                        issue.epic_id,
+                        issue.created_at.to_rfc3339(),
+                        issue.updated_at.to_rfc3339(),
+                        issue.closed_at.map(|dt| dt.to_rfc3339()),
+                    ],
+                )?;
+            }
+            for label in &backup.labels {
+                tx.execute(
+                    "INSERT INTO labels (issue_id, label) VALUES (?1, ?2)",
+                    params![label.issue_id, label.label],
+                )?;
+            }
+            for dep in &backup.dependencies {
+                tx.execute(
+                    "INSERT INTO dependencies (blocker_id, blocked_id) VALUES (?1, ?2)",
+                    params![dep.blocker_id, dep.blocked_id],
+                )?;
+            }
+            for comment in &backup.comments {
+                tx.execute(
+                    "INSERT INTO comments (id, issue_id, content, created_at) VALUES (?1, ?2, ?3, ?4)",
+                    params![comment.id, comment.issue_id, comment.content, comment.created_at.to_rfc3339()],
+                )?;
+            }
+            for session in &backup.sessions {
+                tx.execute(
+                    "INSERT INTO sessions (id, started_at, ended_at, active_issue_id, handoff_notes) \
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        session.id,
+                        session.started_at.to_rfc3339(),
+                        session.ended_at.map(|dt| dt.to_rfc3339()),
+                        session.active_issue_id,
+                        session.handoff_notes,
+                    ],
+                )?;
+            }
+            for entry in &backup.time_entries {
+                tx.execute(
+                    "INSERT INTO time_entries (id, issue_id, started_at, ended_at, duration_seconds) \
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        entry.id,
+                        entry.issue_id,
+                        entry.started_at.to_rfc3339(),
+                        entry.ended_at.map(|dt| dt.to_rfc3339()),
+                        entry.duration_seconds,
+                    ],
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    // Milestones
+    pub fn create_milestone(&self, name: &str, description: Option<&str>) -> Result<i64> {
+        let conn = self.conn()?;
+        let now = Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO milestones (name, description, status, created_at) VALUES (?1, ?2, 'open', ?3)",
+            params![name, description, now],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    pub fn list_milestones(&self, status_filter: Option<&str>) -> Result<Vec<Milestone>> {
+        let conn = self.conn()?;
+        let (sql, status_param) = match status_filter {
+            Some(status) => (
+                "SELECT id, name, description, status, created_at, closed_at FROM milestones WHERE status = ?1 ORDER BY id",
+                Some(status.to_string()),
+            ),
+            None => (
+                "SELECT id, name, description, status, created_at, closed_at FROM milestones ORDER BY id",
+                None,
+            ),
+        };
+
+        let mut stmt = conn.prepare(sql)?;
+        let rows = |row: &rusqlite::Row| -> rusqlite::Result<Milestone> {
+            Ok(Milestone {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                description: row.get(2)?,
+                status: row.get(3)?,
+                created_at: parse_datetime(row.get::<_, String>(4)?),
+                closed_at: row.get::<_, Option<String>>(5)?.map(parse_datetime),
+            })
+        };
+
+        let milestones = match status_param {
+            Some(status) => stmt
+                .query_map(params![status], rows)?
+                .collect::<std::result::Result<Vec<_>, _>>()?,
+            None => stmt
+                .query_map([], rows)?
+                .collect::<std::result::Result<Vec<_>, _>>()?,
+        };
+
+        Ok(milestones)
+    }
+
+    pub fn get_milestone(&self, id: i64) -> Result<Option<Milestone>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, name, description, status, created_at, closed_at FROM milestones WHERE id = ?1",
+        )?;
+
+        let milestone = stmt
+            .query_row([id], |row| {
+                Ok(Milestone {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    description: row.get(2)?,
+                    status: row.get(3)?,
+                    created_at: parse_datetime(row.get::<_, String>(4)?),
+                    closed_at: row.get::<_, Option<String>>(5)?.map(parse_datetime),
+                })
+            })
+            .ok();
+
+        Ok(milestone)
+    }
+
+    pub fn get_milestone_issues(&self, milestone_id: i64) -> Result<Vec<Issue>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT i.id, i.title, i.description, i.status, i.priority, i.parent_id, i.estimate_seconds, i.issue_type, i.epic_id, i.created_at, i.updated_at, i.closed_at \
+             FROM issues i JOIN issue_milestones im ON i.id = im.issue_id WHERE im.milestone_id = ?1 ORDER BY i.id",
+        )?;
+
+        let issues = stmt
+            .query_map([milestone_id], |row| {
+                Ok(Issue {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    description: row.get(2)?,
+                    status: row.get(3)?,
+                    priority: row.get(4)?,
+                    parent_id: row.get(5)?,
+                    estimate_seconds: row.get(6)?,
+                    issue_type: row.get(7)?,
+                    epic_id: row.get(8)?,
+                    created_at: parse_datetime(row.get::<_, String>(9)?),
+                    updated_at: parse_datetime(row.get::<_, String>(10)?),
+                    closed_at: row.get::<_, Option<String>>(11)?.map(parse_datetime),
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(issues)
+    }
+
+    /// The reverse of `get_milestone_issues`: every milestone `issue_id` belongs to. Used by
+    /// `export` to carry milestone membership in the portable JSON snapshot.
+    pub fn get_issue_milestones(&self, issue_id: i64) -> Result<Vec<i64>> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT milestone_id FROM issue_milestones WHERE issue_id = ?1 ORDER BY milestone_id",
+        )?;
+        let milestone_ids = stmt
+            .query_map([issue_id], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<i64>, _>>()?;
+        Ok(milestone_ids)
+    }
+
+    pub fn add_issue_to_milestone(&self, milestone_id: i64, issue_id: i64) -> Result<bool> {
+        let rows = self.conn()?.execute(
+            "INSERT OR IGNORE INTO issue_milestones (issue_id, milestone_id) VALUES (?1, ?2)",
+            params![issue_id, milestone_id],
+        )?;
+        Ok(rows > 0)
+    }
+
+    pub fn remove_issue_from_milestone(&self, milestone_id: i64, issue_id: i64) -> Result<bool> {
+        let rows = self.conn()?.execute(
+            "DELETE FROM issue_milestones WHERE issue_id = ?1 AND milestone_id = ?2",
+            params![issue_id, milestone_id],
+        )?;
+        Ok(rows > 0)
+    }
+
+    pub fn close_milestone(&self, id: i64) -> Result<bool> {
+        let now = Utc::now().to_rfc3339();
+        let rows = self.conn()?.execute(
+            "UPDATE milestones SET status = 'closed', closed_at = ?1 WHERE id = ?2",
+            params![now, id],
+        )?;
+        Ok(rows > 0)
+    }
+
+    pub fn delete_milestone(&self, id: i64) -> Result<bool> {
+        let rows = self.conn()?.execute("DELETE FROM milestones WHERE id = ?1", [id])?;
+        Ok(rows > 0)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupLabel {
+    issue_id: i64,
+    label: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupDependency {
+    blocker_id: i64,
+    blocked_id: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupData {
+    version: i32,
+    issues: Vec<Issue>,
+    labels: Vec<BackupLabel>,
+    dependencies: Vec<BackupDependency>,
+    comments: Vec<Comment>,
+    sessions: Vec<Session>,
+    time_entries: Vec<TimeEntry>,
+}
+
+/// Runs `sql` with no parameters and maps every row with `f`, collecting the results. A small
+/// helper so `export_encrypted` can pull each table into its backup shape without repeating
+/// `prepare` / `query_map` / `collect` boilerplate five times over.
+fn query_all<T>(
+    conn: &Connection,
+    sql: &str,
+    f: impl FnMut(&rusqlite::Row) -> rusqlite::Result<T>,
+) -> Result<Vec<T>> {
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt.query_map([], f)?.collect::<std::result::Result<Vec<T>, _>>()?;
+    Ok(rows)
+}
+
+/// Quotes a single term for safe inclusion in an FTS5 MATCH expression: double-quoted, with
+/// embedded `"` doubled per FTS5's quoting rule, so punctuation, bare hyphens (which FTS5 reads
+/// as column-filter or NOT operators) or stray `:`/`^` never trip the MATCH parser. When
+/// `prefix` is set, a trailing `*` is appended outside the quotes, which FTS5 allows immediately
+/// after a quoted string to request a prefix match.
+fn quote_fts_term(term: &str, prefix: bool) -> String {
+    let escaped = term.replace('"', "\"\"");
+    if prefix {
+        format!("\"{}\"*", escaped)
+    } else {
+        format!("\"{}\"", escaped)
+    }
 }
 
-fn parse_datetime(s: String) -> DateTime<Utc> {
+/// Builds a typo-tolerant FTS5 MATCH expression from free-form user input. Each whitespace
+/// token becomes an OR group of: the token itself, any indexed term within bounded edit
+/// distance of it (looked up in `issues_fts_vocab`/`comments_fts_vocab` — distance 1 for tokens
+/// of 4+ chars, distance 2 for 8+, no fuzzing below that since short tokens have too many
+/// near-neighbors to be useful), and — for the last token only, since it's the one most likely
+/// still being typed — a prefix match. Groups are ANDed together, same as FTS5's default
+/// implicit-AND behavior between bareword tokens.
+fn expand_query_terms(conn: &Connection, query: &str) -> Result<String> {
+    let tokens: Vec<&str> = query.split_whitespace().collect();
+    if tokens.is_empty() {
+        return Ok(String::new());
+    }
+
+    let vocab = load_fts_vocab(conn)?;
+    let last = tokens.len() - 1;
+
+    let mut groups = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let stem: String = token.chars().filter(|c| *c != '"').collect();
+        if stem.is_empty() {
+            continue;
+        }
+        let len = stem.chars().count();
+        let max_distance = if len >= 8 {
+            2
+        } else if len >= 4 {
+            1
+        } else {
+            0
+        };
+
+        let mut variants = vec![quote_fts_term(&stem, false)];
+        if max_distance > 0 {
+            let stem_lower = stem.to_lowercase();
+            for term in &vocab {
+                if term.eq_ignore_ascii_case(&stem) {
+                    continue;
+                }
+                let term_len = term.chars().count();
+                if term_len.abs_diff(len) > max_distance {
+                    continue;
+                }
+                if levenshtein(&stem_lower, &term.to_lowercase()) <= max_distance {
+                    variants.push(quote_fts_term(term, false));
+                }
+            }
+        }
+        if i == last {
+            variants.push(quote_fts_term(&stem, true));
+        }
+
+        groups.push(format!("({})", variants.join(" OR ")));
+    }
+
+    Ok(groups.join(" AND "))
+}
+
+/// Distinct indexed terms across both FTS5 tables, used by `expand_query_terms` as the
+/// candidate pool for edit-distance matching. `fts5vocab`'s `'row'` layout already has one row
+/// per distinct term; the `HashSet` merges terms the two tables share.
+fn load_fts_vocab(conn: &Connection) -> Result<Vec<String>> {
+    let mut terms: HashSet<String> = HashSet::new();
+    for table in ["issues_fts_vocab", "comments_fts_vocab"] {
+        let mut stmt = conn.prepare(&format!("SELECT DISTINCT term FROM {}", table))?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        terms.extend(rows);
+    }
+    Ok(terms.into_iter().collect())
+}
+
+/// Classic Wagner-Fischer edit distance, operating on `char`s (not bytes) so multi-byte
+/// Unicode never gets sliced mid-codepoint the way byte indexing would.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.iter().enumerate() {
+        let mut prev_diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == cb {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j + 1])
+            };
+            prev_diag = cur;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Builds the fallback snippet for a hit that came only from a comment match, where the issue
+/// row has no `snippet()` of its own. Truncates on a `char` boundary so
+/// multi-byte titles never panic, matching the Unicode-robustness the fuzz target checks.
+fn truncate_snippet(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        let truncated: String = text.chars().take(max_chars).collect();
+        format!("{}...", truncated)
+    }
+}
+
+/// Lower ranks first, so `list_scheduled_issues` breaks in-degree ties from most to least
+/// urgent. An unrecognized priority sorts last, after `low`, rather than erroring here — it's
+/// `doctor`'s job to flag data that violates `VALID_PRIORITIES`, not the scheduler's.
+fn priority_rank(priority: &str) -> u8 {
+    match priority {
+        "critical" => 0,
+        "high" => 1,
+        "medium" => 2,
+        "low" => 3,
+        _ => 4,
+    }
+}
+
+/// Depth-first search over `blocks` edges (`blocker_id -> blocked_id`) to answer "is `to`
+/// reachable from `from`?". Used by `add_dependency` to reject an insert that would close a
+/// cycle.
+fn path_exists(conn: &Connection, from: i64, to: i64) -> Result<bool> {
+    let mut visited = HashSet::new();
+    let mut stack = vec![from];
+
+    while let Some(node) = stack.pop() {
+        if node == to {
+            return Ok(true);
+        }
+        if !visited.insert(node) {
+            continue;
+        }
+
+        let mut stmt = conn.prepare("SELECT blocked_id FROM dependencies WHERE blocker_id = ?1")?;
+        let next: Vec<i64> = stmt
+            .query_map([node], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        stack.extend(next);
+    }
+
+    Ok(false)
+}
+
+fn scan_issue_invariants(conn: &Connection) -> Result<Vec<(i64, String, String)>> {
+    let mut stmt = conn.prepare("SELECT id, priority, status FROM issues")?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// Shared by `Database::close_issue` and `commands::batch` so a single-issue close and a
+/// transactional batch close run the exact same SQL.
+pub(crate) fn close_issue_on(conn: &Connection, id: i64) -> Result<bool> {
+    let now = Utc::now().to_rfc3339();
+    let rows = conn.execute(
+        "UPDATE issues SET status = 'closed', closed_at = ?1, updated_at = ?1 WHERE id = ?2",
+        params![now, id],
+    )?;
+    Ok(rows > 0)
+}
+
+/// Shared by `Database::delete_issue` and `commands::batch`.
+pub(crate) fn delete_issue_on(conn: &Connection, id: i64) -> Result<bool> {
+    let rows = conn.execute("DELETE FROM issues WHERE id = ?1", [id])?;
+    Ok(rows > 0)
+}
+
+/// Shared by `Database::add_label` and `commands::batch`.
+pub(crate) fn add_label_on(conn: &Connection, issue_id: i64, label: &str) -> Result<bool> {
+    let result = conn.execute(
+        "INSERT OR IGNORE INTO labels (issue_id, label) VALUES (?1, ?2)",
+        params![issue_id, label],
+    )?;
+    Ok(result > 0)
+}
+
+/// Shared by `Database::remove_label` and `commands::batch`.
+pub(crate) fn remove_label_on(conn: &Connection, issue_id: i64, label: &str) -> Result<bool> {
+    let rows = conn.execute(
+        "DELETE FROM labels WHERE issue_id = ?1 AND label = ?2",
+        params![issue_id, label],
+    )?;
+    Ok(rows > 0)
+}
+
+/// Shared by `Database::add_dependency` and `commands::batch`. Rejects the insert with an error
+/// if `blocker_id` is already reachable from `blocked_id` by following existing `blocks` edges —
+/// adding blocker_id -> blocked_id on top of that would close a cycle.
+pub(crate) fn add_dependency_on(conn: &Connection, blocked_id: i64, blocker_id: i64) -> Result<bool> {
+    if path_exists(conn, blocked_id, blocker_id)? {
+        bail!(
+            "Adding #{} as a blocker of #{} would create a dependency cycle",
+            blocker_id,
+            blocked_id
+        );
+    }
+
+    let result = conn.execute(
+        "INSERT OR IGNORE INTO dependencies (blocker_id, blocked_id) VALUES (?1, ?2)",
+        params![blocker_id, blocked_id],
+    )?;
+    Ok(result > 0)
+}
+
+/// Shared by `Database::update_issue` and `Database::with_transaction` callers so a single-issue
+/// update and a multi-issue transactional update run the exact same SQL.
+pub(crate) fn update_issue_on(
+    conn: &Connection,
+    id: i64,
+    title: Option<&str>,
+    description: Option<&str>,
+    priority: Option<&str>,
+    estimate_seconds: Option<i64>,
+) -> Result<bool> {
+    let previous: Option<(String, Option<String>, String, Option<i64>)> = conn
+        .query_row(
+            "SELECT title, description, priority, estimate_seconds FROM issues WHERE id = ?1",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .optional()?;
+
+    let Some((old_title, old_description, old_priority, old_estimate_seconds)) = previous else {
+        return Ok(false);
+    };
+
+    let now = Utc::now().to_rfc3339();
+    let mut updates = vec!["updated_at = ?1".to_string()];
+    let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = vec![Box::new(now.clone())];
+
+    if let Some(t) = title {
+        updates.push(format!("title = ?{}", params_vec.len() + 1));
+        params_vec.push(Box::new(t.to_string()));
+    }
+
+    if let Some(d) = description {
+        updates.push(format!("description = ?{}", params_vec.len() + 1));
+        params_vec.push(Box::new(d.to_string()));
+    }
+
+    if let Some(p) = priority {
+        updates.push(format!("priority = ?{}", params_vec.len() + 1));
+        params_vec.push(Box::new(p.to_string()));
+    }
+
+    if let Some(e) = estimate_seconds {
+        updates.push(format!("estimate_seconds = ?{}", params_vec.len() + 1));
+        params_vec.push(Box::new(e));
+    }
+
+    params_vec.push(Box::new(id));
+    let sql = format!(
+        "UPDATE issues SET {} WHERE id = ?{}",
+        updates.join(", "),
+        params_vec.len()
+    );
+
+    let params_refs: Vec<&dyn rusqlite::ToSql> = params_vec.iter().map(|p| p.as_ref()).collect();
+    let rows = conn.execute(&sql, params_refs.as_slice())?;
+    if rows == 0 {
+        return Ok(false);
+    }
+
+    // Journal only fields that actually changed, so `undo` doesn't restore a value that was
+    // already current.
+    if let Some(t) = title {
+        if t != old_title {
+            journal_change(conn, id, "title", Some(&old_title), Some(t), &now)?;
+        }
+    }
+    if let Some(d) = description {
+        if Some(d) != old_description.as_deref() {
+            journal_change(conn, id, "description", old_description.as_deref(), Some(d), &now)?;
+        }
+    }
+    if let Some(p) = priority {
+        if p != old_priority {
+            journal_change(conn, id, "priority", Some(&old_priority), Some(p), &now)?;
+        }
+    }
+    if let Some(e) = estimate_seconds {
+        if Some(e) != old_estimate_seconds {
+            journal_change(
+                conn,
+                id,
+                "estimate_seconds",
+                old_estimate_seconds.map(|s| s.to_string()).as_deref(),
+                Some(&e.to_string()),
+                &now,
+            )?;
+        }
+    }
+
+    Ok(true)
+}
+
+fn journal_change(
+    conn: &Connection,
+    issue_id: i64,
+    field: &str,
+    old_value: Option<&str>,
+    new_value: Option<&str>,
+    changed_at: &str,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO issue_history (issue_id, field, old_value, new_value, changed_at) \
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![issue_id, field, old_value, new_value, changed_at],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn parse_datetime(s: String) -> DateTime<Utc> {
     DateTime::parse_from_rfc3339(&s)
         .map(|dt| dt.with_timezone(&Utc))
         .unwrap_or_else(|_| Utc::now())
 }
+
+/// Inserts one issue row for `commands::import`. `id` is `Some` under the `preserve` id
+/// strategy (caller keeps the original id, letting SQLite reject it as a conflict) and `None`
+/// under `remap` (the row gets a fresh autoincremented id). Either way the new id is returned
+/// so the caller can build up an original-id -> new-id translation table.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn insert_imported_issue_on(
+    conn: &Connection,
+    id: Option<i64>,
+    title: &str,
+    description: Option<&str>,
+    status: &str,
+    priority: &str,
+    estimate_seconds: Option<i64>,
+    issue_type: &str,
+    created_at: &str,
+    updated_at: &str,
+    closed_at: Option<&str>,
+) -> Result<i64> {
+    match id {
+        Some(id) => {
+            conn.execute(
+                "INSERT INTO issues (id, title, description, status, priority, estimate_seconds, issue_type, created_at, updated_at, closed_at) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![id, title, description, status, priority, estimate_seconds, issue_type, created_at, updated_at, closed_at],
+            )?;
+            Ok(id)
+        }
+        None => {
+            conn.execute(
+                "INSERT INTO issues (title, description, status, priority, estimate_seconds, issue_type, created_at, updated_at, closed_at) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![title, description, status, priority, estimate_seconds, issue_type, created_at, updated_at, closed_at],
+            )?;
+            Ok(conn.last_insert_rowid())
+        }
+    }
+}
+
+/// Sets `parent_id` on an already-inserted issue. Done as a second pass over the imported
+/// issues, once every original id has a translated row to point at, so parent links that point
+/// forward in the export file still resolve.
+pub(crate) fn set_imported_parent_on(conn: &Connection, id: i64, parent_id: i64) -> Result<()> {
+    conn.execute(
+        "UPDATE issues SET parent_id = ?1 WHERE id = ?2",
+        params![parent_id, id],
+    )?;
+    Ok(())
+}
+
+/// Sets `epic_id` on an already-inserted issue. Same forward-reference reasoning as
+/// `set_imported_parent_on`: epic links are resolved in a second pass once every original id
+/// has a translated row to point at.
+pub(crate) fn set_imported_epic_on(conn: &Connection, id: i64, epic_id: i64) -> Result<()> {
+    conn.execute(
+        "UPDATE issues SET epic_id = ?1 WHERE id = ?2",
+        params![epic_id, id],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn insert_imported_label_on(conn: &Connection, issue_id: i64, label: &str) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO labels (issue_id, label) VALUES (?1, ?2)",
+        params![issue_id, label],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn insert_imported_comment_on(
+    conn: &Connection,
+    issue_id: i64,
+    content: &str,
+    created_at: &str,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO comments (issue_id, content, created_at) VALUES (?1, ?2, ?3)",
+        params![issue_id, content, created_at],
+    )?;
+    Ok(())
+}
+
+/// Unlike `Database::add_dependency`, skips the cycle check: the export was produced from a
+/// database that already enforced acyclicity, so re-validating here would only cost time.
+pub(crate) fn insert_imported_dependency_on(conn: &Connection, blocker_id: i64, blocked_id: i64) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO dependencies (blocker_id, blocked_id) VALUES (?1, ?2)",
+        params![blocker_id, blocked_id],
+    )?;
+    Ok(())
+}
+
+/// Looks up an issue by id within an in-progress transaction, for `commands::import`'s `merge`
+/// strategy to decide whether an incoming row is new or needs a last-writer-wins comparison.
+pub(crate) fn get_issue_on(conn: &Connection, id: i64) -> Result<Option<Issue>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, title, description, status, priority, parent_id, estimate_seconds, issue_type, epic_id, created_at, updated_at, closed_at FROM issues WHERE id = ?1",
+    )?;
+
+    let issue = stmt
+        .query_row([id], |row| {
+            Ok(Issue {
+                id: row.get(0)?,
+                title: row.get(1)?,
+                description: row.get(2)?,
+                status: row.get(3)?,
+                priority: row.get(4)?,
+                parent_id: row.get(5)?,
+                estimate_seconds: row.get(6)?,
+                issue_type: row.get(7)?,
+                epic_id: row.get(8)?,
+                created_at: parse_datetime(row.get::<_, String>(9)?),
+                updated_at: parse_datetime(row.get::<_, String>(10)?),
+                closed_at: row.get::<_, Option<String>>(11)?.map(parse_datetime),
+            })
+        })
+        .optional()?;
+
+    Ok(issue)
+}
+
+/// Overwrites every mutable field of an already-present issue with the incoming row's values.
+/// Used only by `commands::import`'s `merge` strategy once it has decided (via `updated_at`
+/// last-writer-wins) that the incoming side is newer; unlike `update_issue_on` this isn't a user
+/// edit, so it isn't journaled and every field is replaced unconditionally.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn overwrite_merged_issue_on(
+    conn: &Connection,
+    id: i64,
+    title: &str,
+    description: Option<&str>,
+    status: &str,
+    priority: &str,
+    estimate_seconds: Option<i64>,
+    issue_type: &str,
+    created_at: &str,
+    updated_at: &str,
+    closed_at: Option<&str>,
+) -> Result<()> {
+    conn.execute(
+        "UPDATE issues SET title = ?1, description = ?2, status = ?3, priority = ?4, estimate_seconds = ?5, issue_type = ?6, created_at = ?7, updated_at = ?8, closed_at = ?9 WHERE id = ?10",
+        params![title, description, status, priority, estimate_seconds, issue_type, created_at, updated_at, closed_at, id],
+    )?;
+    Ok(())
+}
+
+/// `true` if an identical comment (by issue, content, and timestamp) is already present, so
+/// `commands::import`'s `merge` strategy can union comments from two divergent exports without
+/// duplicating ones both sides already agree on.
+pub(crate) fn comment_exists_on(
+    conn: &Connection,
+    issue_id: i64,
+    content: &str,
+    created_at: &str,
+) -> Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM comments WHERE issue_id = ?1 AND content = ?2 AND created_at = ?3",
+        params![issue_id, content, created_at],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+/// Inserts one milestone row for `commands::import`. `id` is `Some` when the incoming milestone's
+/// id is free (preserved as-is) and `None` when it collides with an existing milestone under a
+/// different name, mirroring `insert_imported_issue_on`'s `preserve`/`remap` split.
+pub(crate) fn insert_imported_milestone_on(
+    conn: &Connection,
+    id: Option<i64>,
+    name: &str,
+    description: Option<&str>,
+    status: &str,
+    created_at: &str,
+    closed_at: Option<&str>,
+) -> Result<i64> {
+    match id {
+        Some(id) => {
+            conn.execute(
+                "INSERT INTO milestones (id, name, description, status, created_at, closed_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![id, name, description, status, created_at, closed_at],
+            )?;
+            Ok(id)
+        }
+        None => {
+            conn.execute(
+                "INSERT INTO milestones (name, description, status, created_at, closed_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![name, description, status, created_at, closed_at],
+            )?;
+            Ok(conn.last_insert_rowid())
+        }
+    }
+}
+
+pub(crate) fn set_imported_issue_milestone_on(
+    conn: &Connection,
+    issue_id: i64,
+    milestone_id: i64,
+) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO issue_milestones (issue_id, milestone_id) VALUES (?1, ?2)",
+        params![issue_id, milestone_id],
+    )?;
+    Ok(())
+}
+
+/// Inserts one session row for `commands::import`. Sessions are append-only history (like
+/// comments), so there's no `preserve`/`remap` choice to make on the caller's part: the id is
+/// kept when free and reassigned when it collides with an unrelated session.
+pub(crate) fn insert_imported_session_on(
+    conn: &Connection,
+    id: Option<i64>,
+    started_at: &str,
+    ended_at: Option<&str>,
+    active_issue_id: Option<i64>,
+    handoff_notes: Option<&str>,
+) -> Result<()> {
+    match id {
+        Some(id) => {
+            conn.execute(
+                "INSERT INTO sessions (id, started_at, ended_at, active_issue_id, handoff_notes) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![id, started_at, ended_at, active_issue_id, handoff_notes],
+            )?;
+        }
+        None => {
+            conn.execute(
+                "INSERT INTO sessions (started_at, ended_at, active_issue_id, handoff_notes) VALUES (?1, ?2, ?3, ?4)",
+                params![started_at, ended_at, active_issue_id, handoff_notes],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn milestone_id_taken_on(conn: &Connection, id: i64) -> Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM milestones WHERE id = ?1",
+        [id],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+pub(crate) fn session_id_taken_on(conn: &Connection, id: i64) -> Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM sessions WHERE id = ?1",
+        [id],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
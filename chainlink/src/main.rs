@@ -1,7 +1,11 @@
+mod analytics;
 mod commands;
+mod crypto;
 mod daemon;
 mod db;
+mod migrations;
 mod models;
+mod serve;
 
 use anyhow::{bail, Context, Result};
 use clap::{Parser, Subcommand};
@@ -17,12 +21,22 @@ use db::Database;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Passphrase to unlock an encrypted database (`init --encrypt`). Only needed for
+    /// encrypted stores; ignored otherwise. Can also be set via CHAINLINK_PASSPHRASE.
+    #[arg(long, global = true, env = "CHAINLINK_PASSPHRASE")]
+    passphrase: Option<String>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Initialize chainlink in the current directory
-    Init,
+    Init {
+        /// Encrypt the issue store at rest via SQLCipher (envelope-encrypted with a
+        /// passphrase-wrapped data-encryption key). Requires the global --passphrase.
+        #[arg(long)]
+        encrypt: bool,
+    },
 
     /// Create a new issue
     Create {
@@ -34,6 +48,15 @@ enum Commands {
         /// Priority (low, medium, high, critical)
         #[arg(short, long, default_value = "medium")]
         priority: String,
+        /// Estimated time to complete (e.g. 2h30m, 1d)
+        #[arg(short, long)]
+        estimate: Option<String>,
+        /// Issue type (task, bug, story, epic)
+        #[arg(short = 'y', long = "type")]
+        issue_type: Option<String>,
+        /// Epic this issue rolls up under
+        #[arg(long)]
+        epic: Option<i64>,
     },
 
     /// Create a subissue under a parent issue
@@ -48,6 +71,15 @@ enum Commands {
         /// Priority (low, medium, high, critical)
         #[arg(short, long, default_value = "medium")]
         priority: String,
+        /// Estimated time to complete (e.g. 2h30m, 1d)
+        #[arg(short, long)]
+        estimate: Option<String>,
+        /// Issue type (task, bug, story, epic)
+        #[arg(short = 'y', long = "type")]
+        issue_type: Option<String>,
+        /// Epic this issue rolls up under
+        #[arg(long)]
+        epic: Option<i64>,
     },
 
     /// List issues
@@ -61,6 +93,9 @@ enum Commands {
         /// Filter by priority
         #[arg(short, long)]
         priority: Option<String>,
+        /// Only show issues whose logged time exceeds their estimate
+        #[arg(long)]
+        over_estimate: bool,
     },
 
     /// Show issue details
@@ -69,10 +104,12 @@ enum Commands {
         id: i64,
     },
 
-    /// Update an issue
+    /// Update one or more issues. Repeat --id to update several at once; all updates apply
+    /// atomically, so if any issue is invalid none of them are changed.
     Update {
-        /// Issue ID
-        id: i64,
+        /// Issue ID to update (repeatable: --id 3 --id 7 --id 12)
+        #[arg(short, long = "id", required = true)]
+        id: Vec<i64>,
         /// New title
         #[arg(short, long)]
         title: Option<String>,
@@ -82,6 +119,27 @@ enum Commands {
         /// New priority
         #[arg(short, long)]
         priority: Option<String>,
+        /// New estimated time to complete (e.g. 2h30m, 1d)
+        #[arg(short, long)]
+        estimate: Option<String>,
+        /// Attach to an epic, independent of --parent
+        #[arg(long)]
+        epic: Option<i64>,
+        /// Keep only the N most recent journaled changes per issue, pruning older history
+        #[arg(long)]
+        keep_history: Option<i64>,
+    },
+
+    /// Revert the most recent update to an issue
+    Undo {
+        /// Issue ID
+        id: i64,
+    },
+
+    /// List journaled changes for an issue
+    History {
+        /// Issue ID
+        id: i64,
     },
 
     /// Close an issue
@@ -96,6 +154,14 @@ enum Commands {
         id: i64,
     },
 
+    /// Cut a dated version from CHANGELOG.md's Unreleased section, inferring the semver bump
+    /// from its entries, and print the resulting version to stdout
+    Release {
+        /// Override the inferred version instead of bumping off the previous one
+        #[arg(long)]
+        version: Option<String>,
+    },
+
     /// Delete an issue
     Delete {
         /// Issue ID
@@ -145,11 +211,66 @@ enum Commands {
         blocker: i64,
     },
 
+    /// Search issue titles, descriptions, and comments
+    Search {
+        /// Search query. Every word must match (small typos allowed; the last word also matches
+        /// as a prefix); quotes and words like AND/OR/NOT are not treated as FTS5 operators
+        query: String,
+        /// Filter by status (open, closed, all)
+        #[arg(short, long)]
+        status: Option<String>,
+        /// Filter by priority
+        #[arg(short, long)]
+        priority: Option<String>,
+        /// Show matched context around each hit
+        #[arg(long)]
+        snippet: bool,
+        /// Maximum number of results to show
+        #[arg(long, default_value_t = 20)]
+        limit: i64,
+        /// Number of results to skip, for paging past `--limit`
+        #[arg(long, default_value_t = 0)]
+        offset: i64,
+    },
+
     /// List blocked issues
-    Blocked,
+    Blocked {
+        /// Group output by epic
+        #[arg(long)]
+        group_by_epic: bool,
+    },
 
     /// List issues ready to work on (no open blockers)
-    Ready,
+    Ready {
+        /// Group output by epic
+        #[arg(long)]
+        group_by_epic: bool,
+    },
+
+    /// Print open issues in dependency-respecting execution order
+    Schedule,
+
+    /// Throughput, cycle-time, backlog, and blocked/ready analytics
+    Stats {
+        /// Only include activity from this date onward (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+        /// Only include activity up to this date (YYYY-MM-DD)
+        #[arg(long)]
+        until: Option<String>,
+        /// Bucket throughput by day or week
+        #[arg(long, default_value = "day")]
+        group_by: String,
+        /// Filter by label
+        #[arg(long)]
+        label: Option<String>,
+        /// Filter by priority
+        #[arg(long)]
+        priority: Option<String>,
+        /// Output as JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Session management
     Session {
@@ -162,6 +283,236 @@ enum Commands {
         #[command(subcommand)]
         action: DaemonCommands,
     },
+
+    /// Serve a read-only HTTP/JSON API (`/issues`, `/tree`, `/next`) plus Prometheus `/metrics`,
+    /// separate from the daemon's read/write admin API
+    Serve {
+        /// Address to bind the read-only API to
+        #[arg(long, default_value = "127.0.0.1:4141")]
+        bind: String,
+    },
+
+    /// Check database integrity and data invariants
+    Doctor {
+        /// Repair what can be safely repaired (out-of-range priorities)
+        #[arg(long)]
+        fix: bool,
+    },
+
+    /// Inspect or apply schema migrations. With no flags, shows the current schema version and
+    /// pending migrations.
+    Migrate {
+        /// Migrate to a specific schema version instead of the latest
+        #[arg(long)]
+        to: Option<i32>,
+    },
+
+    /// Encrypted portable backups
+    Backup {
+        #[command(subcommand)]
+        action: BackupCommands,
+    },
+
+    /// Serialize every issue (with labels, comments, dependencies, and milestone links),
+    /// milestone, and session into a single portable JSON document
+    Export {
+        /// Write to this path instead of stdout
+        output: Option<PathBuf>,
+        /// `json` for a document `import` can restore, `markdown` for a human-readable summary
+        #[arg(long, default_value = "json")]
+        format: String,
+    },
+
+    /// Restore issues, labels, comments, dependencies, milestones, and sessions from a JSON file
+    /// produced by `export`
+    Import {
+        /// Path to the JSON export file
+        path: PathBuf,
+        /// How to assign ids to imported issues: `preserve` keeps the original ids (failing on
+        /// any collision with an existing issue), `remap` assigns fresh ids and rewrites
+        /// parent/blocker/milestone references to match, `merge` folds a divergent export into a
+        /// database that already has some of the same issues (last-writer-wins on conflicts,
+        /// unioning append-only data)
+        #[arg(long, default_value = "preserve")]
+        id_strategy: String,
+    },
+
+    /// Mark tests as run, clearing the reminder `watch` raises on the next code change
+    Tested,
+
+    /// Watch tracked source files and remind when tests are due after a code change
+    Watch {
+        /// Scan once, report, and exit instead of running continuously (for use in hooks)
+        #[arg(long)]
+        once: bool,
+    },
+
+    /// Apply several mutations to one or more issues in a single transaction, reporting a
+    /// per-operation result instead of aborting the whole batch on the first soft failure
+    Batch {
+        /// Close this issue (repeatable)
+        #[arg(long)]
+        close: Vec<i64>,
+        /// Delete this issue (repeatable)
+        #[arg(long)]
+        delete: Vec<i64>,
+        /// Set an issue's priority, as ID=PRIORITY (repeatable)
+        #[arg(long = "priority")]
+        priority: Vec<String>,
+        /// Add a label to an issue, as ID=LABEL (repeatable)
+        #[arg(long = "label")]
+        label: Vec<String>,
+        /// Remove a label from an issue, as ID=LABEL (repeatable)
+        #[arg(long = "unlabel")]
+        unlabel: Vec<String>,
+        /// Block ID on BLOCKER_ID, as ID=BLOCKER_ID (repeatable)
+        #[arg(long = "block-on")]
+        block_on: Vec<String>,
+        /// Read a JSON array of operations from stdin instead of the flags above
+        #[arg(long)]
+        stdin: bool,
+    },
+
+    /// Log time spent on an issue without starting a timer
+    Log {
+        /// Issue ID
+        id: i64,
+        /// Time spent (e.g. 2h30m, 1d, 90s)
+        duration: String,
+    },
+
+    /// Start tracking time on an issue
+    Start {
+        /// Issue ID
+        id: i64,
+        /// If another issue has an active timer, close it out (up to its last heartbeat) instead
+        /// of refusing to start
+        #[arg(long)]
+        resume: bool,
+    },
+
+    /// Stop the active timer and record the elapsed time
+    Stop,
+
+    /// Show the currently running timer, if any
+    Status {
+        /// Detect an orphaned active timer (no heartbeat for a while) and close it into history
+        #[arg(long)]
+        recover: bool,
+    },
+
+    /// List tracked time sessions: every one for an issue if given, or the most recent across
+    /// all issues otherwise
+    Sessions {
+        /// Issue ID
+        id: Option<i64>,
+    },
+
+    /// Record time after the fact without a running timer; a leading '-' subtracts instead
+    Track {
+        /// Issue ID
+        id: i64,
+        /// Signed time offset (e.g. 1h30m, 90m, -30m)
+        offset: String,
+    },
+
+    /// Timesheet report of tracked time, grouped by issue and by day/week
+    Timesheet {
+        /// Start of the range: YYYY-MM-DD, or today/week/month
+        #[arg(long)]
+        since: Option<String>,
+        /// End of the range: YYYY-MM-DD, or today/week/month
+        #[arg(long)]
+        until: Option<String>,
+    },
+
+    /// Manage epics, which roll up child issues independent of the subissue chain
+    Epic {
+        #[command(subcommand)]
+        action: EpicCommands,
+    },
+
+    /// Group issues toward a shared release and generate categorized release notes from them
+    Milestone {
+        #[command(subcommand)]
+        action: MilestoneCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum EpicCommands {
+    /// Create a new epic
+    Create {
+        /// Epic title
+        title: String,
+        /// Epic description
+        #[arg(short, long)]
+        description: Option<String>,
+        /// Priority (low, medium, high, critical)
+        #[arg(short, long, default_value = "medium")]
+        priority: String,
+    },
+    /// Show an epic's rolled-up child status/priority counts
+    Show {
+        /// Epic issue ID
+        id: i64,
+    },
+}
+
+#[derive(Subcommand)]
+enum MilestoneCommands {
+    /// Create a new milestone
+    Create {
+        /// Milestone name
+        name: String,
+        /// Milestone description
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+    /// List milestones
+    List {
+        /// Filter by status (open, closed)
+        #[arg(long)]
+        status: Option<String>,
+    },
+    /// Show a milestone and its issues
+    Show {
+        /// Milestone ID
+        id: i64,
+    },
+    /// Add one or more issues to a milestone
+    Add {
+        /// Milestone ID
+        milestone_id: i64,
+        /// Issue IDs to add
+        issue_ids: Vec<i64>,
+    },
+    /// Remove an issue from a milestone
+    Remove {
+        /// Milestone ID
+        milestone_id: i64,
+        /// Issue ID
+        issue_id: i64,
+    },
+    /// Close a milestone
+    Close {
+        /// Milestone ID
+        id: i64,
+    },
+    /// Delete a milestone
+    Delete {
+        /// Milestone ID
+        id: i64,
+    },
+    /// Print the milestone's closed issues as categorized Markdown release notes
+    Notes {
+        /// Milestone ID
+        id: i64,
+        /// Also write the notes into CHANGELOG.md under a new version heading named after the
+        /// milestone
+        #[arg(long)]
+        write: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -183,10 +534,35 @@ enum SessionCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum BackupCommands {
+    /// Encrypt every issue, label, dependency, comment, session, and time entry into a
+    /// portable backup file
+    Export {
+        /// Path to write the encrypted backup to
+        path: PathBuf,
+        /// Passphrase the backup is encrypted under (also required to import it)
+        #[arg(long)]
+        passphrase: String,
+    },
+    /// Restore a backup produced by `backup export`, replacing all current data
+    Import {
+        /// Path to the encrypted backup file
+        path: PathBuf,
+        /// Passphrase the backup was encrypted under
+        #[arg(long)]
+        passphrase: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum DaemonCommands {
     /// Start the background daemon
-    Start,
+    Start {
+        /// Host an admin HTTP API at this address (e.g. 127.0.0.1:4040)
+        #[arg(long)]
+        listen: Option<String>,
+    },
     /// Stop the background daemon
     Stop,
     /// Check daemon status
@@ -196,6 +572,8 @@ enum DaemonCommands {
     Run {
         #[arg(long)]
         dir: PathBuf,
+        #[arg(long)]
+        listen: Option<String>,
     },
 }
 
@@ -214,28 +592,58 @@ fn find_chainlink_dir() -> Result<PathBuf> {
     }
 }
 
-fn get_db() -> Result<Database> {
+/// Opens the current directory's `issues.db`, routing to `Database::open_encrypted` when a
+/// `.key` sidecar shows the store was created with `init --encrypt` — so every command, not
+/// just `init`, can reopen an encrypted database.
+fn get_db(passphrase: Option<&str>) -> Result<Database> {
     let chainlink_dir = find_chainlink_dir()?;
     let db_path = chainlink_dir.join("issues.db");
-    Database::open(&db_path).context("Failed to open database")
+
+    if Database::is_encrypted(&db_path) {
+        let passphrase = passphrase.context(
+            "This database is encrypted. Pass --passphrase or set CHAINLINK_PASSPHRASE.",
+        )?;
+        Database::open_encrypted(&db_path, passphrase).context("Failed to open encrypted database")
+    } else {
+        Database::open(&db_path).context("Failed to open database")
+    }
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    let passphrase = cli.passphrase.clone();
+
     match cli.command {
-        Commands::Init => {
+        Commands::Init { encrypt } => {
+            if encrypt && passphrase.is_none() {
+                bail!("--encrypt requires --passphrase");
+            }
+            if !encrypt && passphrase.is_some() {
+                bail!("--passphrase has no effect without --encrypt");
+            }
             let cwd = env::current_dir()?;
-            commands::init::run(&cwd)
+            commands::init::run(&cwd, passphrase.as_deref())
         }
 
         Commands::Create {
             title,
             description,
             priority,
+            estimate,
+            issue_type,
+            epic,
         } => {
-            let db = get_db()?;
-            commands::create::run(&db, &title, description.as_deref(), &priority)
+            let db = get_db(passphrase.as_deref())?;
+            commands::create::run(
+                &db,
+                &title,
+                description.as_deref(),
+                &priority,
+                estimate.as_deref(),
+                issue_type.as_deref(),
+                epic,
+            )
         }
 
         Commands::Subissue {
@@ -243,22 +651,35 @@ fn main() -> Result<()> {
             title,
             description,
             priority,
+            estimate,
+            issue_type,
+            epic,
         } => {
-            let db = get_db()?;
-            commands::create::run_subissue(&db, parent, &title, description.as_deref(), &priority)
+            let db = get_db(passphrase.as_deref())?;
+            commands::create::run_subissue(
+                &db,
+                parent,
+                &title,
+                description.as_deref(),
+                &priority,
+                estimate.as_deref(),
+                issue_type.as_deref(),
+                epic,
+            )
         }
 
         Commands::List {
             status,
             label,
             priority,
+            over_estimate,
         } => {
-            let db = get_db()?;
-            commands::list::run(&db, Some(&status), label.as_deref(), priority.as_deref())
+            let db = get_db(passphrase.as_deref())?;
+            commands::list::run(&db, Some(&status), label.as_deref(), priority.as_deref(), over_estimate)
         }
 
         Commands::Show { id } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             commands::show::run(&db, id)
         }
 
@@ -267,69 +688,135 @@ fn main() -> Result<()> {
             title,
             description,
             priority,
+            estimate,
+            epic,
+            keep_history,
         } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             commands::update::run(
                 &db,
-                id,
+                &id,
                 title.as_deref(),
                 description.as_deref(),
                 priority.as_deref(),
+                estimate.as_deref(),
+                epic,
+                keep_history,
             )
         }
 
+        Commands::Undo { id } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::history::undo(&db, id)
+        }
+
+        Commands::History { id } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::history::list(&db, id)
+        }
+
         Commands::Close { id } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             commands::status::close(&db, id)
         }
 
         Commands::Reopen { id } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             commands::status::reopen(&db, id)
         }
 
+        Commands::Release { version } => {
+            let chainlink_dir = find_chainlink_dir()?;
+            commands::release::run(&chainlink_dir, version.as_deref())
+        }
+
         Commands::Delete { id, force } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             commands::delete::run(&db, id, force)
         }
 
         Commands::Comment { id, text } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             commands::comment::run(&db, id, &text)
         }
 
         Commands::Label { id, label } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             commands::label::add(&db, id, &label)
         }
 
         Commands::Unlabel { id, label } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             commands::label::remove(&db, id, &label)
         }
 
         Commands::Block { id, blocker } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             commands::deps::block(&db, id, blocker)
         }
 
         Commands::Unblock { id, blocker } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             commands::deps::unblock(&db, id, blocker)
         }
 
-        Commands::Blocked => {
-            let db = get_db()?;
-            commands::deps::list_blocked(&db)
+        Commands::Search {
+            query,
+            status,
+            priority,
+            snippet,
+            limit,
+            offset,
+        } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::search::run(
+                &db,
+                &query,
+                status.as_deref(),
+                priority.as_deref(),
+                snippet,
+                limit,
+                offset,
+            )
         }
 
-        Commands::Ready => {
-            let db = get_db()?;
-            commands::deps::list_ready(&db)
+        Commands::Blocked { group_by_epic } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::deps::list_blocked(&db, group_by_epic)
+        }
+
+        Commands::Ready { group_by_epic } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::deps::list_ready(&db, group_by_epic)
+        }
+
+        Commands::Schedule => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::deps::schedule(&db)
+        }
+
+        Commands::Stats {
+            since,
+            until,
+            group_by,
+            label,
+            priority,
+            json,
+        } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::stats::run(
+                &db,
+                since.as_deref(),
+                until.as_deref(),
+                &group_by,
+                label.as_deref(),
+                priority.as_deref(),
+                json,
+            )
         }
 
         Commands::Session { action } => {
-            let db = get_db()?;
+            let db = get_db(passphrase.as_deref())?;
             match action {
                 SessionCommands::Start => commands::session::start(&db),
                 SessionCommands::End { notes } => commands::session::end(&db, notes.as_deref()),
@@ -340,9 +827,9 @@ fn main() -> Result<()> {
 
         Commands::Daemon { action } => {
             match action {
-                DaemonCommands::Start => {
+                DaemonCommands::Start { listen } => {
                     let chainlink_dir = find_chainlink_dir()?;
-                    daemon::start(&chainlink_dir)
+                    daemon::start(&chainlink_dir, listen.as_deref())
                 }
                 DaemonCommands::Stop => {
                     let chainlink_dir = find_chainlink_dir()?;
@@ -352,8 +839,143 @@ fn main() -> Result<()> {
                     let chainlink_dir = find_chainlink_dir()?;
                     daemon::status(&chainlink_dir)
                 }
-                DaemonCommands::Run { dir } => {
-                    daemon::run_daemon(&dir)
+                DaemonCommands::Run { dir, listen } => {
+                    daemon::run_daemon(&dir, listen.as_deref())
+                }
+            }
+        }
+
+        Commands::Doctor { fix } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::doctor::run(&db, fix)
+        }
+
+        Commands::Migrate { to } => {
+            let db = get_db(passphrase.as_deref())?;
+            match to {
+                Some(version) => commands::migrate::to_version(&db, version),
+                None => commands::migrate::status(&db),
+            }
+        }
+
+        Commands::Backup { action } => {
+            let db = get_db(passphrase.as_deref())?;
+            match action {
+                BackupCommands::Export { path, passphrase } => {
+                    commands::backup::export(&db, &path, &passphrase)
+                }
+                BackupCommands::Import { path, passphrase } => {
+                    commands::backup::import(&db, &path, &passphrase)
+                }
+            }
+        }
+
+        Commands::Export { output, format } => {
+            let db = get_db(passphrase.as_deref())?;
+            let output = output.as_deref().and_then(|p| p.to_str());
+            match format.as_str() {
+                "json" => commands::export::run_json(&db, output),
+                "markdown" => commands::export::run_markdown(&db, output),
+                other => bail!("Unknown export format '{}'. Must be one of: json, markdown", other),
+            }
+        }
+
+        Commands::Import { path, id_strategy } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::import::run(&db, &path, &id_strategy)
+        }
+
+        Commands::Tested => {
+            let chainlink_dir = find_chainlink_dir()?;
+            commands::tested::run(&chainlink_dir)
+        }
+
+        Commands::Watch { once } => {
+            let chainlink_dir = find_chainlink_dir()?;
+            let db = get_db(passphrase.as_deref())?;
+            commands::watch::run(&chainlink_dir, &db, once)
+        }
+
+        Commands::Serve { bind } => {
+            let db = get_db(passphrase.as_deref())?;
+            serve::run(&bind, db)
+        }
+
+        Commands::Batch { close, delete, priority, label, unlabel, block_on, stdin } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::batch::run(&db, &close, &delete, &priority, &label, &unlabel, &block_on, stdin)
+        }
+
+        Commands::Log { id, duration } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::log::run(&db, id, &duration)
+        }
+
+        Commands::Start { id, resume } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::timer::start(&db, id, resume)
+        }
+
+        Commands::Stop => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::timer::stop(&db)
+        }
+
+        Commands::Status { recover } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::timer::status(&db, recover)
+        }
+
+        Commands::Sessions { id } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::timer::log(&db, id)
+        }
+
+        Commands::Track { id, offset } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::timer::track(&db, id, &offset)
+        }
+
+        Commands::Timesheet { since, until } => {
+            let db = get_db(passphrase.as_deref())?;
+            commands::timesheet::report(&db, since.as_deref(), until.as_deref())
+        }
+
+        Commands::Epic { action } => {
+            let db = get_db(passphrase.as_deref())?;
+            match action {
+                EpicCommands::Create {
+                    title,
+                    description,
+                    priority,
+                } => commands::epic::create(&db, &title, description.as_deref(), &priority),
+                EpicCommands::Show { id } => commands::epic::show(&db, id),
+            }
+        }
+
+        Commands::Milestone { action } => {
+            let db = get_db(passphrase.as_deref())?;
+            match action {
+                MilestoneCommands::Create { name, description } => {
+                    commands::milestone::create(&db, &name, description.as_deref())
+                }
+                MilestoneCommands::List { status } => {
+                    commands::milestone::list(&db, status.as_deref())
+                }
+                MilestoneCommands::Show { id } => commands::milestone::show(&db, id),
+                MilestoneCommands::Add {
+                    milestone_id,
+                    issue_ids,
+                } => commands::milestone::add(&db, milestone_id, &issue_ids),
+                MilestoneCommands::Remove {
+                    milestone_id,
+                    issue_id,
+                } => commands::milestone::remove(&db, milestone_id, issue_id),
+                MilestoneCommands::Close { id } => commands::milestone::close(&db, id),
+                MilestoneCommands::Delete { id } => commands::milestone::delete(&db, id),
+                MilestoneCommands::Notes { id, write } => {
+                    let chainlink_dir = find_chainlink_dir()?;
+                    commands::milestone::notes(&db, id, write, &chainlink_dir)
                 }
             }
         }
@@ -0,0 +1,494 @@
+//! Embedded, versioned schema migrations, modeled on refinery/sqlx-style migration runners.
+//!
+//! Each migration is a pair of `.sql` files embedded at compile time: an "up" script applied
+//! in order when opening the database, and a "down" script used by `migrate --to` to step
+//! backwards. Applied migrations are recorded in `schema_migrations` along with a checksum of
+//! their up-script, so a migration that's edited after release is caught rather than silently
+//! skipped or re-applied.
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use rusqlite::{params, Connection};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+pub struct Migration {
+    pub version: i32,
+    pub name: &'static str,
+    pub up: &'static str,
+    pub down: &'static str,
+}
+
+impl Migration {
+    /// A migration that applies no SQL in either direction. Reserves a version number without
+    /// committing to what it does, so a version that's backported or reordered later doesn't
+    /// have to renumber every migration after it — the placeholder keeps the sequence
+    /// contiguous in the meantime.
+    pub const fn placeholder(version: i32, name: &'static str) -> Migration {
+        Migration { version, name, up: "", down: "" }
+    }
+}
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial",
+        up: include_str!("../migrations/V1__initial.sql"),
+        down: include_str!("../migrations/V1__initial.down.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "fts_search",
+        up: include_str!("../migrations/V2__fts_search.sql"),
+        down: include_str!("../migrations/V2__fts_search.down.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "issue_history",
+        up: include_str!("../migrations/V3__issue_history.sql"),
+        down: include_str!("../migrations/V3__issue_history.down.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "comments_fts",
+        up: include_str!("../migrations/V4__comments_fts.sql"),
+        down: include_str!("../migrations/V4__comments_fts.down.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "time_estimates",
+        up: include_str!("../migrations/V5__time_estimates.sql"),
+        down: include_str!("../migrations/V5__time_estimates.down.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "issue_types_and_epics",
+        up: include_str!("../migrations/V6__issue_types_and_epics.sql"),
+        down: include_str!("../migrations/V6__issue_types_and_epics.down.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "fts_vocab",
+        up: include_str!("../migrations/V7__fts_vocab.sql"),
+        down: include_str!("../migrations/V7__fts_vocab.down.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "milestones",
+        up: include_str!("../migrations/V8__milestones.sql"),
+        down: include_str!("../migrations/V8__milestones.down.sql"),
+    },
+    Migration {
+        version: 9,
+        name: "timer_heartbeat",
+        up: include_str!("../migrations/V9__timer_heartbeat.sql"),
+        down: include_str!("../migrations/V9__timer_heartbeat.down.sql"),
+    },
+];
+
+pub struct AppliedMigration {
+    pub version: i32,
+    pub name: &'static str,
+    pub applied: bool,
+}
+
+fn checksum(sql: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    sql.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+fn ensure_metadata_table(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            checksum TEXT NOT NULL,
+            applied_at TEXT NOT NULL
+        )",
+    )?;
+    Ok(())
+}
+
+/// Returns the applied (version, checksum) pairs in ascending order, after verifying that
+/// versions are contiguous starting at 1 and that no applied migration's SQL has drifted
+/// from what's embedded in this binary.
+fn verify_applied(conn: &Connection, migrations: &[Migration]) -> Result<Vec<(i32, String)>> {
+    let mut stmt =
+        conn.prepare("SELECT version, checksum FROM schema_migrations ORDER BY version")?;
+    let applied = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<std::result::Result<Vec<(i32, String)>, _>>()?;
+
+    let mut expected = 1;
+    for (version, stored_checksum) in &applied {
+        if *version != expected {
+            bail!(
+                "schema_migrations is corrupt: expected version {} but found {}",
+                expected,
+                version
+            );
+        }
+        let migration = match migrations.iter().find(|m| m.version == *version) {
+            Some(migration) => migration,
+            None if *version > migrations.len() as i32 => bail!(
+                "database schema is at version {}, but this chainlink binary only knows \
+                 migrations up to version {}; upgrade chainlink before opening this database",
+                version,
+                migrations.len()
+            ),
+            None => bail!(
+                "applied migration V{} is missing from the embedded migration list",
+                version
+            ),
+        };
+        if &checksum(migration.up) != stored_checksum {
+            bail!(
+                "migration V{}__{} has changed since it was applied; embedded migrations must never be edited after release",
+                version,
+                migration.name
+            );
+        }
+        expected += 1;
+    }
+
+    Ok(applied)
+}
+
+/// Applies any migrations with a version greater than the current one, each inside its own
+/// transaction, recording it in `schema_migrations` on success.
+pub fn run(conn: &Connection) -> Result<()> {
+    run_against(conn, MIGRATIONS)
+}
+
+/// The guts of `run`, parameterized over the migration list so tests can exercise ordering and
+/// placeholder-version behavior without touching the real embedded `MIGRATIONS`.
+fn run_against(conn: &Connection, migrations: &[Migration]) -> Result<()> {
+    ensure_metadata_table(conn)?;
+    let applied = verify_applied(conn, migrations)?;
+    let mut next_version = applied.len() as i32 + 1;
+
+    for migration in migrations.iter().filter(|m| m.version >= next_version) {
+        if migration.version != next_version {
+            bail!(
+                "migration versions must be contiguous with no gaps; missing V{}",
+                next_version
+            );
+        }
+
+        conn.execute_batch("BEGIN;")
+            .context("failed to begin migration transaction")?;
+
+        let result = conn.execute_batch(migration.up).and_then(|_| {
+            conn.execute(
+                "INSERT INTO schema_migrations (version, name, checksum, applied_at) VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    migration.version,
+                    migration.name,
+                    checksum(migration.up),
+                    Utc::now().to_rfc3339(),
+                ],
+            )
+        });
+
+        match result {
+            Ok(_) => conn.execute_batch("COMMIT;")?,
+            Err(e) => {
+                conn.execute_batch("ROLLBACK;").ok();
+                return Err(e).with_context(|| {
+                    format!("failed to apply migration V{}__{}", migration.version, migration.name)
+                });
+            }
+        }
+
+        next_version += 1;
+    }
+
+    sync_user_version_against(conn, migrations)
+}
+
+/// Mirrors the count of applied migrations into `PRAGMA user_version`. `schema_migrations`
+/// (with its per-migration checksums) remains the source of truth this crate reads from; this
+/// is purely so an external tool poking the database file with `sqlite3` or `PRAGMA
+/// user_version` sees a sane, up-to-date number instead of whatever `init_schema` left behind
+/// in older databases.
+fn sync_user_version_against(conn: &Connection, migrations: &[Migration]) -> Result<()> {
+    let current_version = verify_applied(conn, migrations)?.len() as i32;
+    conn.execute_batch(&format!("PRAGMA user_version = {};", current_version))?;
+    Ok(())
+}
+
+/// Rolls the database back or forward to exactly `target_version`, running down-migrations
+/// or up-migrations as needed. `target_version` of 0 means "no migrations applied".
+pub fn migrate_to(conn: &Connection, target_version: i32) -> Result<()> {
+    migrate_to_against(conn, MIGRATIONS, target_version)
+}
+
+/// The guts of `migrate_to`, parameterized the same way `run_against` is.
+fn migrate_to_against(conn: &Connection, migrations: &[Migration], target_version: i32) -> Result<()> {
+    ensure_metadata_table(conn)?;
+    let applied = verify_applied(conn, migrations)?;
+    let current_version = applied.len() as i32;
+
+    if target_version > migrations.len() as i32 || target_version < 0 {
+        bail!(
+            "target version {} is out of range (0..={})",
+            target_version,
+            migrations.len()
+        );
+    }
+
+    if target_version > current_version {
+        for migration in migrations
+            .iter()
+            .filter(|m| m.version > current_version && m.version <= target_version)
+        {
+            conn.execute_batch("BEGIN;")?;
+            let result = conn.execute_batch(migration.up).and_then(|_| {
+                conn.execute(
+                    "INSERT INTO schema_migrations (version, name, checksum, applied_at) VALUES (?1, ?2, ?3, ?4)",
+                    params![migration.version, migration.name, checksum(migration.up), Utc::now().to_rfc3339()],
+                )
+            });
+            match result {
+                Ok(_) => conn.execute_batch("COMMIT;")?,
+                Err(e) => {
+                    conn.execute_batch("ROLLBACK;").ok();
+                    return Err(e.into());
+                }
+            }
+        }
+    } else {
+        for migration in migrations
+            .iter()
+            .filter(|m| m.version <= current_version && m.version > target_version)
+            .rev()
+        {
+            conn.execute_batch("BEGIN;")?;
+            let result = conn.execute_batch(migration.down).and_then(|_| {
+                conn.execute(
+                    "DELETE FROM schema_migrations WHERE version = ?1",
+                    params![migration.version],
+                )
+            });
+            match result {
+                Ok(_) => conn.execute_batch("COMMIT;")?,
+                Err(e) => {
+                    conn.execute_batch("ROLLBACK;").ok();
+                    return Err(e.into());
+                }
+            }
+        }
+    }
+
+    sync_user_version_against(conn, migrations)
+}
+
+/// Lists every embedded migration alongside whether it's currently applied, for bare `migrate`.
+pub fn status(conn: &Connection) -> Result<Vec<AppliedMigration>> {
+    status_against(conn, MIGRATIONS)
+}
+
+/// The guts of `status`, parameterized the same way `run_against` is.
+fn status_against(conn: &Connection, migrations: &[Migration]) -> Result<Vec<AppliedMigration>> {
+    ensure_metadata_table(conn)?;
+    let applied = verify_applied(conn, migrations)?;
+    let applied_versions: Vec<i32> = applied.iter().map(|(v, _)| *v).collect();
+
+    Ok(migrations
+        .iter()
+        .map(|m| AppliedMigration {
+            version: m.version,
+            name: m.name,
+            applied: applied_versions.contains(&m.version),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_conn() -> Connection {
+        Connection::open_in_memory().unwrap()
+    }
+
+    #[test]
+    fn test_run_applies_all_migrations() {
+        let conn = open_conn();
+        run(&conn).unwrap();
+
+        let statuses = status(&conn).unwrap();
+        assert!(statuses.iter().all(|s| s.applied));
+    }
+
+    #[test]
+    fn test_run_is_idempotent() {
+        let conn = open_conn();
+        run(&conn).unwrap();
+        run(&conn).unwrap();
+
+        let count: i32 = conn
+            .query_row("SELECT COUNT(*) FROM schema_migrations", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, MIGRATIONS.len() as i32);
+    }
+
+    #[test]
+    fn test_migrate_to_rolls_back() {
+        let conn = open_conn();
+        run(&conn).unwrap();
+
+        migrate_to(&conn, 1).unwrap();
+        let statuses = status(&conn).unwrap();
+        assert!(statuses[0].applied);
+        assert!(!statuses[1].applied);
+
+        // issues_fts should no longer exist after the v2 down-migration
+        let result: Result<i64, _> = conn.query_row(
+            "SELECT COUNT(*) FROM issues_fts",
+            [],
+            |r| r.get(0),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_migrate_to_reapplies() {
+        let conn = open_conn();
+        run(&conn).unwrap();
+        migrate_to(&conn, 0).unwrap();
+        migrate_to(&conn, MIGRATIONS.len() as i32).unwrap();
+
+        let statuses = status(&conn).unwrap();
+        assert!(statuses.iter().all(|s| s.applied));
+    }
+
+    #[test]
+    fn test_migrate_to_out_of_range() {
+        let conn = open_conn();
+        run(&conn).unwrap();
+
+        let result = migrate_to(&conn, 99);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_run_rejects_database_newer_than_binary() {
+        let conn = open_conn();
+        run(&conn).unwrap();
+
+        // Simulate a database migrated by a newer binary that knows about a V{n+1} migration
+        // this one has never heard of.
+        conn.execute(
+            "INSERT INTO schema_migrations (version, name, checksum, applied_at) VALUES (?1, ?2, ?3, ?4)",
+            params![MIGRATIONS.len() as i32 + 1, "from_the_future", "deadbeef", Utc::now().to_rfc3339()],
+        )
+        .unwrap();
+
+        let result = run(&conn);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("upgrade chainlink"));
+    }
+
+    #[test]
+    fn test_user_version_tracks_applied_count() {
+        let conn = open_conn();
+        run(&conn).unwrap();
+
+        let user_version: i32 = conn.query_row("PRAGMA user_version", [], |r| r.get(0)).unwrap();
+        assert_eq!(user_version, MIGRATIONS.len() as i32);
+
+        migrate_to(&conn, 1).unwrap();
+        let user_version: i32 = conn.query_row("PRAGMA user_version", [], |r| r.get(0)).unwrap();
+        assert_eq!(user_version, 1);
+    }
+
+    /// Seeds a row on a database stamped at schema V1 (the shape `Database::open` would have
+    /// produced before any later migration existed), then runs every later migration and
+    /// checks the row's original columns survived untouched and the new columns added along
+    /// the way landed on sane defaults — not just that each migration's DDL applied cleanly.
+    #[test]
+    fn test_upgrading_from_v1_preserves_seeded_data() {
+        let conn = open_conn();
+        migrate_to(&conn, 1).unwrap();
+
+        conn.execute(
+            "INSERT INTO issues (title, description, status, priority, created_at, updated_at) \
+             VALUES ('Seeded issue', 'from before the later migrations existed', 'open', 'high', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z')",
+            [],
+        )
+        .unwrap();
+
+        migrate_to(&conn, MIGRATIONS.len() as i32).unwrap();
+
+        let statuses = status(&conn).unwrap();
+        assert!(statuses.iter().all(|s| s.applied));
+
+        let (title, description, priority, issue_type, epic_id, estimate_seconds): (
+            String,
+            String,
+            String,
+            String,
+            Option<i64>,
+            Option<i64>,
+        ) = conn
+            .query_row(
+                "SELECT title, description, priority, issue_type, epic_id, estimate_seconds FROM issues WHERE title = 'Seeded issue'",
+                [],
+                |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        row.get(5)?,
+                    ))
+                },
+            )
+            .unwrap();
+
+        assert_eq!(title, "Seeded issue");
+        assert_eq!(description, "from before the later migrations existed");
+        assert_eq!(priority, "high");
+        assert_eq!(issue_type, "task");
+        assert_eq!(epic_id, None);
+        assert_eq!(estimate_seconds, None);
+    }
+
+    /// A placeholder reserves its version number without doing anything, so later migrations
+    /// keep their version numbers even if the slot it reserved never gets filled.
+    #[test]
+    fn test_placeholder_migration_applies_as_a_no_op() {
+        let migrations = vec![
+            Migration { version: 1, name: "initial", up: "CREATE TABLE t (id INTEGER);", down: "DROP TABLE t;" },
+            Migration::placeholder(2, "reserved"),
+            Migration { version: 3, name: "adds_column", up: "ALTER TABLE t ADD COLUMN x INTEGER;", down: "" },
+        ];
+
+        let conn = open_conn();
+        run_against(&conn, &migrations).unwrap();
+
+        let statuses = status_against(&conn, &migrations).unwrap();
+        assert!(statuses.iter().all(|s| s.applied));
+
+        // The placeholder left no trace beyond its row in schema_migrations.
+        conn.execute("INSERT INTO t (id, x) VALUES (1, 2)", []).unwrap();
+    }
+
+    #[test]
+    fn test_placeholder_migration_is_idempotent_and_reversible() {
+        let migrations =
+            vec![Migration { version: 1, name: "initial", up: "CREATE TABLE t (id INTEGER);", down: "DROP TABLE t;" }, Migration::placeholder(2, "reserved")];
+
+        let conn = open_conn();
+        run_against(&conn, &migrations).unwrap();
+        run_against(&conn, &migrations).unwrap();
+
+        migrate_to_against(&conn, &migrations, 1).unwrap();
+        let statuses = status_against(&conn, &migrations).unwrap();
+        assert!(statuses[0].applied);
+        assert!(!statuses[1].applied);
+    }
+}
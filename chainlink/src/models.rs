@@ -9,11 +9,74 @@ pub struct Issue {
     pub status: String,
     pub priority: String,
     pub parent_id: Option<i64>,
+    /// Absent in backups taken before estimates existed, so it defaults to `None` rather than
+    /// failing to deserialize them.
+    #[serde(default)]
+    pub estimate_seconds: Option<i64>,
+    /// One of `task`/`bug`/`story`/`epic`, validated like `priority` in `commands::create`.
+    /// Absent in backups taken before issue types existed, so it defaults to `"task"`.
+    #[serde(default = "default_issue_type")]
+    pub issue_type: String,
+    /// The epic this issue rolls up under, independent of `parent_id`. Absent in backups taken
+    /// before epics existed, so it defaults to `None`.
+    #[serde(default)]
+    pub epic_id: Option<i64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub closed_at: Option<DateTime<Utc>>,
 }
 
+fn default_issue_type() -> String {
+    "task".to_string()
+}
+
+/// A single `Database::search_issues` match. `score` is the underlying `bm25()` value, so
+/// *lower* (more negative) means more relevant, matching SQLite FTS5's own convention rather
+/// than a conventional "higher is better" score. `snippet` is always populated — built from the
+/// best-matching span via FTS5's `snippet()`, falling back to a plain prefix of the title when
+/// the hit came only from a comment match, which gives `snippet()` no span in the issue's text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub issue: Issue,
+    pub score: f64,
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub id: i64,
+    pub issue_id: i64,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub changed_at: DateTime<Utc>,
+    pub reverted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthIssue {
+    pub issue_id: Option<i64>,
+    pub problem: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub integrity_errors: Vec<String>,
+    pub foreign_key_errors: Vec<String>,
+    pub schema_version: i32,
+    pub schema_total: i32,
+    pub problems: Vec<HealthIssue>,
+}
+
+impl HealthReport {
+    pub fn is_healthy(&self) -> bool {
+        self.integrity_errors.is_empty()
+            && self.foreign_key_errors.is_empty()
+            && self.schema_version == self.schema_total
+            && self.problems.is_empty()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Comment {
     pub id: i64,
@@ -30,3 +93,22 @@ pub struct Session {
     pub active_issue_id: Option<i64>,
     pub handoff_notes: Option<String>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Milestone {
+    pub id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub id: i64,
+    pub issue_id: i64,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_seconds: Option<i64>,
+}
@@ -0,0 +1,36 @@
+//! `chainlink serve`: a read-only HTTP/JSON view onto the issue store, separate from the
+//! daemon's read/write admin API (`daemon::api_server`). It exposes the same query logic
+//! `list`/`tree`/`next` already print to a terminal, plus a Prometheus `/metrics` endpoint, so
+//! dashboards and scrapers have something to point at without going through the admin API's
+//! write surface.
+
+mod router;
+
+use anyhow::{Context, Result};
+use std::net::TcpListener;
+
+use crate::db::Database;
+
+/// Binds `addr` and serves forever, handing each connection to `router::handle` on its own
+/// thread — the same accept-loop shape as `daemon::api_server::serve`.
+pub fn run(addr: &str, db: Database) -> Result<()> {
+    let listener =
+        TcpListener::bind(addr).with_context(|| format!("Failed to bind chainlink serve to {}", addr))?;
+
+    println!("Serving read-only API on {}", addr);
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(_) => continue,
+        };
+        let db = db.clone();
+        std::thread::spawn(move || {
+            if let Err(err) = router::handle(stream, &db) {
+                eprintln!("serve: {:#}", err);
+            }
+        });
+    }
+
+    Ok(())
+}
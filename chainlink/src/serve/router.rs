@@ -0,0 +1,222 @@
+//! Routes the four read-only endpoints `serve` exposes. Hand-rolled over `std::net::TcpStream`
+//! like `daemon::router`, just trimmed to GET-only parsing since nothing here accepts a body.
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+
+use crate::analytics;
+use crate::commands::export::export_issue;
+use crate::commands::next::score_ready_issues;
+use crate::db::Database;
+use crate::models::Issue;
+
+struct HttpRequest {
+    method: String,
+    segments: Vec<String>,
+    query: HashMap<String, String>,
+}
+
+pub fn handle(stream: TcpStream, db: &Database) -> Result<()> {
+    let mut reader = BufReader::new(stream.try_clone().context("Failed to clone connection")?);
+    let (status, body) = match read_request(&mut reader) {
+        Ok(request) => route(db, &request),
+        Err(_) => (400, Body::Json(json!({"error": "Malformed request"}))),
+    };
+    write_response(stream, status, &body)
+}
+
+/// A route either returns JSON (everything but `/metrics`) or Prometheus text exposition format.
+enum Body {
+    Json(Value),
+    Text(String),
+}
+
+fn route(db: &Database, req: &HttpRequest) -> (u16, Body) {
+    let segments: Vec<&str> = req.segments.iter().map(String::as_str).collect();
+
+    if req.method != "GET" {
+        return (405, Body::Json(json!({"error": "chainlink serve is read-only"})));
+    }
+
+    let outcome = match segments.as_slice() {
+        ["issues"] => list_issues(db, req).map(Body::Json),
+        ["tree"] => tree(db, req).map(Body::Json),
+        ["next"] => next(db).map(Body::Json),
+        ["metrics"] => metrics(db).map(Body::Text),
+        _ => return (404, Body::Json(json!({"error": "Not found"}))),
+    };
+
+    match outcome {
+        Ok(body) => (200, body),
+        Err(err) => (500, Body::Json(json!({"error": err.to_string()}))),
+    }
+}
+
+fn list_issues(db: &Database, req: &HttpRequest) -> Result<Value> {
+    let status = req.query.get("status").map(String::as_str);
+    let label = req.query.get("label").map(String::as_str);
+    let priority = req.query.get("priority").map(String::as_str);
+
+    let issues = db.list_issues(status, label, priority)?;
+    exported_list(db, &issues)
+}
+
+/// The same nested shape `tree::run` prints, built from `db.list_issues`/`db.get_subissues`
+/// instead of formatted lines.
+fn tree(db: &Database, req: &HttpRequest) -> Result<Value> {
+    let status = req.query.get("status").map(String::as_str);
+    let all_issues = db.list_issues(status, None, None)?;
+    let top_level: Vec<&Issue> = all_issues.iter().filter(|i| i.parent_id.is_none()).collect();
+
+    let nodes = top_level
+        .into_iter()
+        .map(|issue| tree_node(db, issue))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(json!(nodes))
+}
+
+fn tree_node(db: &Database, issue: &Issue) -> Result<Value> {
+    let subissues = db.get_subissues(issue.id)?;
+    let children = subissues.iter().map(|sub| tree_node(db, sub)).collect::<Result<Vec<_>>>()?;
+    Ok(json!({
+        "id": issue.id,
+        "title": issue.title,
+        "status": issue.status,
+        "priority": issue.priority,
+        "children": children,
+    }))
+}
+
+/// The same ranking `next::run` recommends, as JSON: the top pick plus up to three runners-up.
+fn next(db: &Database) -> Result<Value> {
+    let scored = score_ready_issues(db)?;
+
+    let entries: Vec<Value> = scored
+        .iter()
+        .take(4)
+        .map(|(issue, score, progress)| {
+            json!({
+                "id": issue.id,
+                "title": issue.title,
+                "priority": issue.priority,
+                "score": score,
+                "progress": progress.map(|(closed, total)| json!({"closed": closed, "total": total})),
+            })
+        })
+        .collect();
+
+    Ok(json!({
+        "next": entries.first().cloned(),
+        "also_ready": entries.iter().skip(1).cloned().collect::<Vec<_>>(),
+    }))
+}
+
+/// Prometheus text exposition format: open/closed/blocked/ready counts, a per-priority
+/// breakdown of the open backlog, and total tracked time.
+fn metrics(db: &Database) -> Result<String> {
+    let conn = db.conn()?;
+    let open = db.list_issues(Some("open"), None, None)?.len();
+    let closed = db.list_issues(Some("closed"), None, None)?.len();
+    let blocked = db.list_blocked_issues()?.len();
+    let ready = db.list_ready_issues()?.len();
+    let by_priority = analytics::open_backlog_by_priority(&conn, None, None)?;
+    let tracked_seconds = db.get_total_tracked_time()?;
+
+    let mut out = String::new();
+    out.push_str("# HELP chainlink_issues_total Number of issues by status.\n");
+    out.push_str("# TYPE chainlink_issues_total gauge\n");
+    out.push_str(&format!("chainlink_issues_total{{status=\"open\"}} {}\n", open));
+    out.push_str(&format!("chainlink_issues_total{{status=\"closed\"}} {}\n", closed));
+
+    out.push_str("# HELP chainlink_issues_blocked Number of issues blocked by an open dependency.\n");
+    out.push_str("# TYPE chainlink_issues_blocked gauge\n");
+    out.push_str(&format!("chainlink_issues_blocked {}\n", blocked));
+
+    out.push_str("# HELP chainlink_issues_ready Number of open, unblocked issues.\n");
+    out.push_str("# TYPE chainlink_issues_ready gauge\n");
+    out.push_str(&format!("chainlink_issues_ready {}\n", ready));
+
+    out.push_str("# HELP chainlink_open_issues_by_priority Open issues grouped by priority.\n");
+    out.push_str("# TYPE chainlink_open_issues_by_priority gauge\n");
+    for bucket in &by_priority {
+        out.push_str(&format!(
+            "chainlink_open_issues_by_priority{{priority=\"{}\"}} {}\n",
+            bucket.priority, bucket.count
+        ));
+    }
+
+    out.push_str("# HELP chainlink_tracked_seconds_total Total tracked time across every issue, in seconds.\n");
+    out.push_str("# TYPE chainlink_tracked_seconds_total counter\n");
+    out.push_str(&format!("chainlink_tracked_seconds_total {}\n", tracked_seconds));
+
+    Ok(out)
+}
+
+fn exported_list(db: &Database, issues: &[Issue]) -> Result<Value> {
+    let exported = issues.iter().map(|issue| export_issue(db, issue)).collect::<Result<Vec<_>>>()?;
+    Ok(json!(exported))
+}
+
+fn read_request(reader: &mut BufReader<TcpStream>) -> Result<HttpRequest> {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().context("Missing HTTP method")?.to_string();
+    let target = parts.next().context("Missing request target")?.to_string();
+
+    // Read (and discard) headers; a read-only GET API has no body to parse.
+    loop {
+        let mut header_line = String::new();
+        if reader.read_line(&mut header_line)? == 0 {
+            break;
+        }
+        if header_line.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    let (path, query_string) = target.split_once('?').unwrap_or((target.as_str(), ""));
+    let segments = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect();
+    let query = query_string
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+    Ok(HttpRequest { method, segments, query })
+}
+
+fn write_response(mut stream: TcpStream, status: u16, body: &Body) -> Result<()> {
+    let (content_type, payload) = match body {
+        Body::Json(value) => ("application/json", serde_json::to_vec(value)?),
+        Body::Text(text) => ("text/plain; version=0.0.4", text.as_bytes().to_vec()),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        status_text(status),
+        content_type,
+        payload.len()
+    )?;
+    stream.write_all(&payload)?;
+    Ok(())
+}
+
+fn status_text(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        500 => "Internal Server Error",
+        _ => "Unknown",
+    }
+}